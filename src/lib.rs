@@ -1,4 +1,11 @@
-use std::io::ErrorKind;
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
 
 /// Maven coordinates part separator.
 const MAVEN_COORDINATES_SPLITTER: &str = ":";
@@ -9,14 +16,189 @@ pub const MAVEN_STANDARD_PACKAGING: &str = "jar";
 /// Splitter used to separate artifact name from version and classifier in file name.
 const FILENAME_SPLITTER: &str = "-";
 
+/// Splitter used to separate build metadata from the rest of the version.
+const BUILD_METADATA_SPLITTER: &str = "+";
+
+/// Splitter used to separate numeric segments of the version.
+const VERSION_SEGMENT_SPLITTER: &str = ".";
+
 // Splitter used to separate packaging (extension) from the base name of the artifact.
 const EXTENSION_SPLITTER: &str = ".";
 
+/// Extension of the marker files Maven writes into the local repository on failed downloads.
+const LAST_UPDATED_EXTENSION: &str = "lastUpdated";
+
+/// Path of the Artifactory storage REST API relative to the server address.
+const ARTIFACTORY_STORAGE_API: &str = "api/storage/";
+
+/// Classifier used by Maven Shade Plugin for the shaded artifact.
+const SHADED_CLASSIFIER: &str = "shaded";
+
+/// Classifier of the sources artifact.
+const SOURCES_CLASSIFIER: &str = "sources";
+
+/// Classifier of the Javadoc artifact.
+const JAVADOC_CLASSIFIER: &str = "javadoc";
+
+/// Packaging of the project object model (POM) files.
+const POM_PACKAGING: &str = "pom";
+
+/// File name of the repository metadata file.
+const MAVEN_METADATA_FILE: &str = "maven-metadata.xml";
+
+/// Fragment of the Nexus Repository UI URL browsing the repository.
+const NEXUS_BROWSE_FRAGMENT: &str = "#browse/browse:";
+
+/// Checksums Maven publishes alongside each artifact by default.
+const STANDARD_CHECKSUMS: [ChecksumAlgorithm; 2] =
+    [ChecksumAlgorithm::Md5, ChecksumAlgorithm::Sha1];
+
+/// Checksums Gradle publishes alongside each artifact.
+const GRADLE_CHECKSUMS: [ChecksumAlgorithm; 4] = [
+    ChecksumAlgorithm::Md5,
+    ChecksumAlgorithm::Sha1,
+    ChecksumAlgorithm::Sha256,
+    ChecksumAlgorithm::Sha512,
+];
+
+/// Packaging of the Gradle module metadata files.
+const GRADLE_MODULE_PACKAGING: &str = "module";
+
+/// Extension of the detached PGP signature files.
+const SIGNATURE_EXTENSION: &str = "asc";
+
+/// Classifiers commonly used by Maven plugins, used to detect swapped components.
+const KNOWN_CLASSIFIERS: [&str; 8] = [
+    "sources",
+    "javadoc",
+    "tests",
+    "test-sources",
+    "shaded",
+    "all",
+    "client",
+    "natives",
+];
+
+/// Packagings commonly used in Maven repositories, used to detect swapped components.
+const KNOWN_PACKAGINGS: [&str; 11] = [
+    "jar",
+    "pom",
+    "war",
+    "ear",
+    "rar",
+    "aar",
+    "zip",
+    "module",
+    "bundle",
+    "maven-plugin",
+    "tar.gz",
+];
+
+/// Packagings of the artifacts that are packaged as JARs, despite different packaging.
+const JAR_PACKAGINGS: [&str; 4] = ["bundle", "maven-plugin", "ejb", "test-jar"];
+
+/// Extensions of the compressed files, which are combined with `tar` extension before them.
+const COMPRESSION_EXTENSIONS: [&str; 3] = ["gz", "bz2", "xz"];
+
+/// Extension of the tarballs, which are usually compressed, like `tar.gz`.
+const TAR_EXTENSION: &str = "tar";
+
+/// Version qualifiers (in lower-case, without trailing numbers) recognized when parsing file
+/// names, so that they are kept in the version rather than taken for the classifier.
+const KNOWN_QUALIFIERS: [&str; 11] = [
+    "alpha",
+    "beta",
+    "milestone",
+    "m",
+    "rc",
+    "cr",
+    "snapshot",
+    "final",
+    "ga",
+    "release",
+    "sp",
+];
+
+/// Characters that would break path or URL resolution if present in any of the components.
+const FORBIDDEN_CHARACTERS: [char; 3] = [':', '/', '\\'];
+
+/// Version qualifiers in the order of their precedence, where aliases share the rank, and
+/// `None` stands for the release itself.
+const QUALIFIER_RANKS: [&[&str]; 7] = [
+    &["alpha", "a"],
+    &["beta", "b"],
+    &["milestone", "m"],
+    &["rc", "cr"],
+    &["snapshot"],
+    &["", "ga", "final", "release"],
+    &["sp"],
+];
+
+/// Field name of the group ID in field maps.
+const FIELD_GROUP_ID: &str = "groupId";
+
+/// Field name of the artifact ID in field maps.
+const FIELD_ARTIFACT_ID: &str = "artifactId";
+
+/// Field name of the version in field maps.
+const FIELD_VERSION: &str = "version";
+
+/// Field name of the packaging in field maps.
+const FIELD_TYPE: &str = "type";
+
+/// Field name of the classifier in field maps.
+const FIELD_CLASSIFIER: &str = "classifier";
+
 // Default separator
 const DEFAULT_SEPARATOR: char = '/';
 
+/// Version label used to denote snapshot versions.
+const SNAPSHOT_LABEL: &str = "SNAPSHOT";
+
+/// Splitter used to separate keys from values in properties files.
+const PROPERTIES_SPLITTER: char = '=';
+
+/// Prefixes of the comment lines in properties files.
+const PROPERTIES_COMMENTS: [char; 2] = ['#', '!'];
+
+/// Prefix Maven adds to the lines of its output.
+const MAVEN_LOG_PREFIX: &str = "[INFO]";
+
+/// Characters used to draw the tree in `mvn dependency:tree` output.
+const DEPENDENCY_TREE_GLYPHS: [char; 5] = ['|', '+', '-', '\\', ' '];
+
+/// Prefix of the package URLs referring to Maven packages.
+const MAVEN_PURL_PREFIX: &str = "pkg:maven/";
+
+/// Scheme prefix some resolvers add to the coordinates.
+const MAVEN_SCHEME_PREFIX: &str = "maven:";
+
+/// Separator between the scheme and the rest of URL-like notations, like `ivy://`.
+const URL_SCHEME_SPLITTER: &str = "://";
+
+/// Splitter used to separate attributes in Coursier dependency notation.
+const COURSIER_ATTRIBUTES_SPLITTER: &str = ",";
+
+/// Splitter used to separate coordinates from the configurations in Gradle lockfile entries.
+const GRADLE_LOCKFILE_SPLITTER: char = '=';
+
+/// Splitter between the Gradle dependency notation and the artifact extension.
+const GRADLE_EXTENSION_SPLITTER: char = '@';
+
+/// Splitter between the name and the version in `$group:$artifact@$version` notation.
+const AT_VERSION_SPLITTER: char = '@';
+
+/// Prefix of the excluded entries in dependency lists.
+const EXCLUSION_MARKER: char = '!';
+
+/// Separator between the components of the slug.
+const SLUG_SEPARATOR: &str = "__";
+
+/// Prefix of the comment lines in Gradle lockfiles.
+const GRADLE_LOCKFILE_COMMENT: char = '#';
+
 /// Standard Maven Coordinates.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Coordinates {
     /// Per Maven documentation, group ID uniquely identifies the project among all the other
     /// projects. It should, but not required to, follow [Java package name rules][java-naming].
@@ -62,10 +244,14 @@ pub struct Coordinates {
     /// # Library note
     ///
     /// This library follows semantic versioning, therefore it will denote version label to the
-    /// version itself. To denote version label, at last dash (`-`) within the version, the split
+    /// version itself. To denote version label, at first dash (`-`) within the version, the split
     /// will be made, where everything before the dash will make it into the [`version`][0], and
-    /// anything that follows it into the [`version_label`][1]. To get complete version, including
-    /// the label, you'll have to use [`full_version`][2] method.
+    /// anything that follows it into the [`version_label`][1]. For example, `1.0-alpha-1` is split
+    /// into `1.0` and `alpha-1`. To get complete version, including the label, you'll have to use
+    /// [`full_version`][2] method.
+    ///
+    /// Versions produced by `git describe` (like `1.0-5-gabc123`) are kept intact in the
+    /// [`version`][0], since dashes in them do not denote the label.
     ///
     /// [0]: Coordinates::version
     /// [1]: Coordinates::version_label
@@ -76,7 +262,8 @@ pub struct Coordinates {
     /// Version used both in the name of the artifact file, as well as separating directory.
     pub version: String,
 
-    /// Denoted by last dash in version part of the coordinates, label for this version (if any).
+    /// Denoted by first dash in version part of the coordinates, label for this version (if any),
+    /// like `alpha-1` in `1.0-alpha-1`.
     ///
     /// To get complete version, including the label itself, use [`full_version`][0] method.
     ///
@@ -92,6 +279,24 @@ pub struct Coordinates {
     /// Version label used in the name of the artifact file, following the version.
     pub version_label: Option<String>,
 
+    /// Denoted by first plus sign in version part of the coordinates, [semantic versioning build
+    /// metadata](https://semver.org/#spec-item-10) for this version (if any).
+    ///
+    /// Build metadata is not considered when comparing versions, see
+    /// [`same_version`][0] method.
+    ///
+    /// [0]: Coordinates::same_version
+    ///
+    /// # Examples
+    ///
+    /// - `build.5` to refer to fifth build.
+    /// - `sha.5114f85` to refer to the commit the artifact was built from.
+    ///
+    /// # Usage
+    ///
+    /// Build metadata used in the name of the artifact file, following the version label.
+    pub build_metadata: Option<String>,
+
     /// Packaging is essentially an extension of the artifact. If not specified in coordinates,
     /// it is assumed to be `jar`.
     ///
@@ -118,33 +323,172 @@ pub struct Coordinates {
     ///
     /// Classifier is added after the version number when resolving the artifact file name.
     pub classifier: Option<String>,
+
+    /// Whether packaging was explicitly specified in the parsed coordinates, so that explicit
+    /// standard packaging (`jar`) is preserved when converting back to string. Ignored when
+    /// comparing and hashing coordinates.
+    explicit_packaging: bool,
 }
 
-impl ToString for Coordinates {
-    fn to_string(&self) -> String {
-        // $groupId:$artifactId:$version:$packaging:$classifier
+impl PartialEq for Coordinates {
+    fn eq(&self, other: &Self) -> bool {
+        self.group_id == other.group_id
+            && self.artifact_id == other.artifact_id
+            && self.version == other.version
+            && self.version_label == other.version_label
+            && self.build_metadata == other.build_metadata
+            && self.packaging == other.packaging
+            && self.classifier == other.classifier
+    }
+}
+
+impl Eq for Coordinates {}
+
+impl Hash for Coordinates {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.group_id.hash(state);
+        self.artifact_id.hash(state);
+        self.version.hash(state);
+        self.version_label.hash(state);
+        self.build_metadata.hash(state);
+        self.packaging.hash(state);
+        self.classifier.hash(state);
+    }
+}
 
-        let mut string = String::new();
+impl fmt::Display for Coordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // $groupId:$artifactId:$version:$packaging:$classifier
 
-        string += &self.group_id;
+        f.write_str(&self.group_id)?;
 
-        string += MAVEN_COORDINATES_SPLITTER;
-        string += &self.artifact_id;
+        f.write_str(MAVEN_COORDINATES_SPLITTER)?;
+        f.write_str(&self.artifact_id)?;
 
-        string += MAVEN_COORDINATES_SPLITTER;
-        string += self.full_version().as_str();
+        f.write_str(MAVEN_COORDINATES_SPLITTER)?;
+        f.write_str(self.full_version().as_str())?;
 
-        if !self.packaging.eq(MAVEN_STANDARD_PACKAGING) || self.classifier.is_some() {
-            string += MAVEN_COORDINATES_SPLITTER;
-            string += &self.packaging;
+        if self.explicit_packaging
+            || !self.packaging.eq(MAVEN_STANDARD_PACKAGING)
+            || self.classifier.is_some()
+        {
+            f.write_str(MAVEN_COORDINATES_SPLITTER)?;
+            f.write_str(&self.packaging)?;
 
             if let Some(classifier) = &self.classifier {
-                string += MAVEN_COORDINATES_SPLITTER;
-                string += classifier;
+                f.write_str(MAVEN_COORDINATES_SPLITTER)?;
+                f.write_str(classifier)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when coordinates cannot be parsed or are invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoordinatesError {
+    /// Group ID is not present.
+    MissingGroupId,
+
+    /// Artifact ID is not present.
+    MissingArtifactId,
+
+    /// Version is not present.
+    MissingVersion,
+
+    /// Component is present, but empty. Contains the name of the component, like `artifact ID`.
+    EmptyComponent(&'static str),
+
+    /// Component does not follow Maven naming rules. Contains the name of the component.
+    MalformedComponent(&'static str),
+
+    /// Coordinates string has more than five components. Contains the original input.
+    TooManyComponents(String),
+
+    /// Input does not follow the expected notation. Contains the original input.
+    InvalidNotation(String),
+
+    /// Input contains an unclosed double quote. Contains the original input.
+    UnbalancedQuotes(String),
+
+    /// Coordinates are written in URL-like notation with explicit scheme (like `ivy://`). Contains
+    /// the scheme.
+    UnsupportedScheme(String),
+
+    /// Packaging is not among the allowed ones. Contains the packaging.
+    DisallowedPackaging(String),
+
+    /// Classifier is set for `pom` packaging, which cannot be classified.
+    ClassifiedPom,
+}
+
+impl fmt::Display for CoordinatesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinatesError::MissingGroupId => f.write_str("group ID is missing"),
+            CoordinatesError::MissingArtifactId => f.write_str("artifact ID is missing"),
+            CoordinatesError::MissingVersion => f.write_str("version is missing"),
+            CoordinatesError::EmptyComponent(component) => write!(f, "{} is empty", component),
+            CoordinatesError::MalformedComponent(component) => {
+                write!(f, "{} is malformed", component)
             }
+            CoordinatesError::TooManyComponents(input) => write!(
+                f,
+                "too many components in `{}`, expected at most five",
+                input
+            ),
+            CoordinatesError::InvalidNotation(input) => write!(f, "invalid notation `{}`", input),
+            CoordinatesError::UnbalancedQuotes(input) => {
+                write!(f, "unbalanced quotes in `{}`", input)
+            }
+            CoordinatesError::UnsupportedScheme(scheme) => {
+                write!(f, "unsupported scheme `{}`", scheme)
+            }
+            CoordinatesError::DisallowedPackaging(packaging) => {
+                write!(f, "packaging `{}` is not allowed", packaging)
+            }
+            CoordinatesError::ClassifiedPom => f.write_str("POM cannot have a classifier"),
         }
+    }
+}
+
+impl Error for CoordinatesError {}
+
+impl PartialOrd for Coordinates {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Coordinates {
+    /// Compares group ID and artifact ID lexically, then version according to the Maven rules
+    /// (see [`compare_versions`]), ignoring build metadata. Remaining fields are compared
+    /// lexically to break ties.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let version = |c: &Coordinates| match &c.version_label {
+            Some(version_label) => format!("{}{}{}", c.version, FILENAME_SPLITTER, version_label),
+            None => c.version.to_string(),
+        };
+
+        self.group_id
+            .cmp(&other.group_id)
+            .then_with(|| self.artifact_id.cmp(&other.artifact_id))
+            .then_with(|| compare_versions(&version(self), &version(other)))
+            .then_with(|| self.version.cmp(&other.version))
+            .then_with(|| self.version_label.cmp(&other.version_label))
+            .then_with(|| self.build_metadata.cmp(&other.build_metadata))
+            .then_with(|| self.packaging.cmp(&other.packaging))
+            .then_with(|| self.classifier.cmp(&other.classifier))
+    }
+}
+
+impl FromStr for Coordinates {
+    type Err = CoordinatesError;
 
-        string
+    /// Parses coordinates string, see [`Coordinates::new`].
+    fn from_str(coordinates: &str) -> Result<Self, Self::Err> {
+        CoordinatesRef::parse(coordinates).map(CoordinatesRef::into_owned)
     }
 }
 
@@ -154,285 +498,7074 @@ impl Coordinates {
     /// # Arguments
     ///
     /// * `coordinates`: Maven coordinates string, which follows the format:
+    ///   `[maven:]$groupId:$artifactId:$version[:$packaging[:$classifier]]`. Since `maven` is also
+    ///   a legacy group ID, the `maven:` scheme prefix is only stripped when the input cannot be
+    ///   read as coordinates with `maven` group ID, so `maven:junit:junit:4.12` has a scheme,
+    ///   while `maven:maven-model:3.0:pom` does not. No other schemes are recognized.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If coordinates string is correct and parsed, this will be `Ok(Coordinates)`. Otherwise
+    /// `Err(CoordinatesError)` will be returned: `MissingGroupId`, `MissingArtifactId` or
+    /// `MissingVersion` if there are less than three components, `EmptyComponent` if any of them
+    /// is empty, and `TooManyComponents` if there are more than five components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{Coordinates, CoordinatesError};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    ///
+    /// let error = Coordinates::new("io.github.brawaru:artifact").unwrap_err();
+    /// assert_eq!(error, CoordinatesError::MissingVersion);
+    /// ```
+    pub fn new<S: Into<String>>(coordinates: S) -> Result<Self, CoordinatesError> {
+        coordinates.into().parse()
+    }
+
+    /// Creates new coordinates struct from the coordinates string and validates it.
+    ///
+    /// Unlike [`new`][0], this rejects coordinates that do not pass [`validate`][1], as well as
+    /// URL-like notations with explicit scheme (like `ivy://`).
+    ///
+    /// Other prefixes cannot be told apart from the group ID (`eclipse:org.eclipse.jdt.core:3.1.1`
+    /// is a valid coordinate with the `eclipse` group ID), so they are parsed as such.
+    ///
+    /// [0]: Coordinates::new
+    /// [1]: Coordinates::validate
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Maven coordinates string, which follows the format:
     ///   `$groupId:$artifactId:$version[:$packaging[:$classifier]]`.
     ///
     /// # Returns
     ///
-    /// Result<Coordinates, ErrorKind>
+    /// Result<Coordinates, CoordinatesError>
     ///
-    /// If coordinates string is correct and parsed, this will be `Ok(Coordinates)`, otherwise
-    /// `Err(ErrorKind::InvalidInput)` will be returned.
+    /// If coordinates string is correct and valid, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned, `UnsupportedScheme` for URL-like notations.
     ///
     /// # Examples
     ///
     /// ```
     /// use maven_coordinates::Coordinates;
     ///
-    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// assert!(Coordinates::new_strict("io.github.brawaru:   :1.0.0").is_err());
     /// ```
-    pub fn new<S: Into<String>>(coordinates: S) -> Result<Self, ErrorKind> {
+    pub fn new_strict<S: Into<String>>(coordinates: S) -> Result<Self, CoordinatesError> {
         let coordinates = coordinates.into();
 
-        let mut parts = coordinates.split(MAVEN_COORDINATES_SPLITTER);
-
-        // $groupId:$packageId:$version-$qualifier:$packaging:$classifier
-
-        let group_id = parts.next();
-        let artifact_id = parts.next();
-        let version_part = parts.next();
-
-        // Group ID, artifact ID and version are a mandatory
-        if group_id.is_none() || artifact_id.is_none() || version_part.is_none() {
-            return Err(ErrorKind::InvalidInput);
+        if let Some(scheme) = Coordinates::url_scheme(&coordinates) {
+            return Err(CoordinatesError::UnsupportedScheme(scheme.to_string()));
         }
 
-        let (version, version_qualifier) = Coordinates::split_version(version_part.unwrap());
-        let packaging = parts.next();
-        let classifier = parts.next();
+        let coordinates = Coordinates::new(coordinates)?;
 
-        Ok(Self {
-            group_id: group_id.unwrap().to_string(),
-            artifact_id: artifact_id.unwrap().to_string(),
-            version: version.to_string(),
-            version_label: version_qualifier.map_or(None, |q| Some(q.to_string())),
-            packaging: packaging.unwrap_or(MAVEN_STANDARD_PACKAGING).to_string(),
-            classifier: classifier.map_or(None, |s| Some(s.to_string())),
-        })
+        coordinates.validate()?;
+
+        Ok(coordinates)
     }
 
-    /// Splits version into the slices of version itself and the qualifier part.
+    /// Strips the `maven:` scheme prefix some resolvers add to the coordinates.
+    ///
+    /// Since `maven` is also a legacy group ID, prefix is only stripped when the rest of the
+    /// coordinates still has at least three components (`$groupId:$artifactId:$version`), and the
+    /// input as a whole is not valid coordinates with `maven` group ID. The latter is the case
+    /// when it has more than five components, or its packaging component looks like a version
+    /// (starts with a digit), like `4.12` in `maven:junit:junit:4.12`.
     ///
     /// # Arguments
     ///
-    /// * `version`: Source version string to split
+    /// * `coordinates`: Maven coordinates string.
     ///
-    /// returns: (&str, Option<&str>)
-    fn split_version(version: &str) -> (&str, Option<&str>) {
-        if let Some(split_index) = version.rfind(FILENAME_SPLITTER) {
-            (&version[..split_index], Some(&version[split_index + 1..]))
+    /// returns: &str
+    fn strip_maven_scheme(coordinates: &str) -> &str {
+        let rest = match coordinates.strip_prefix(MAVEN_SCHEME_PREFIX) {
+            Some(rest) => rest,
+            None => return coordinates,
+        };
+
+        let components = coordinates.split(MAVEN_COORDINATES_SPLITTER).count();
+        let packaging = coordinates.split(MAVEN_COORDINATES_SPLITTER).nth(3);
+
+        let is_legacy_group = components <= 5
+            && !packaging
+                .is_some_and(|packaging| packaging.starts_with(|c: char| c.is_ascii_digit()));
+
+        if components >= 4 && !is_legacy_group {
+            rest
         } else {
-            (&version, None)
+            coordinates
         }
     }
 
-    /// Returns complete version (including the label).
-    ///
-    /// returns: String
+    /// Returns scheme of the URL-like notation (like `ivy://`), which is the only form of scheme
+    /// that cannot be confused with the group ID.
     ///
-    /// # Examples
+    /// # Arguments
     ///
-    /// ```
-    /// use maven_coordinates::Coordinates;
+    /// * `coordinates`: Maven coordinates string.
     ///
-    /// Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap().full_version();
-    /// // => "1.0.0-SNAPSHOT"
-    /// ```
-    pub fn full_version(&self) -> String {
-        let mut full_version = self.version.to_string();
+    /// returns: `Option<&str>`
+    fn url_scheme(coordinates: &str) -> Option<&str> {
+        let split_index = coordinates.find(URL_SCHEME_SPLITTER)?;
+        let scheme = &coordinates[..split_index];
 
-        if let Some(version_qualifier) = &self.version_label {
-            full_version += FILENAME_SPLITTER;
-            full_version += version_qualifier;
-        }
+        let is_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'));
 
-        full_version
+        if is_scheme {
+            Some(scheme)
+        } else {
+            None
+        }
     }
 
-    /// Returns base file name for this artifact.
+    /// Creates new coordinates struct from the coordinates string, allowing only certain
+    /// packagings.
     ///
-    /// returns: String
+    /// Standard packaging (`jar`) is always allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Maven coordinates string, which follows the format:
+    ///   `$groupId:$artifactId:$version[:$packaging[:$classifier]]`.
+    /// * `allowed`: Allowed packagings.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If coordinates string is correct and its packaging is allowed, this will be
+    /// `Ok(Coordinates)`, otherwise `Err(CoordinatesError)` will be returned,
+    /// `DisallowedPackaging` for packagings not in `allowed`.
     ///
     /// # Examples
     ///
     /// ```
     /// use maven_coordinates::Coordinates;
     ///
-    /// Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT:jar:sources").unwrap().file_basename();
-    /// // => "artifact-1.0.0-SNAPSHOT-sources"
+    /// assert!(Coordinates::new_with_allowed_packagings("io.github.brawaru:artifact:1.0.0:war", &["pom"]).is_err());
     /// ```
-    pub fn file_basename(&self) -> String {
-        let mut file_name = self.artifact_id.to_string();
+    pub fn new_with_allowed_packagings(
+        coordinates: &str,
+        allowed: &[&str],
+    ) -> Result<Self, CoordinatesError> {
+        let coordinates = Coordinates::new(coordinates)?;
 
-        file_name += FILENAME_SPLITTER;
-        file_name += &self.full_version();
+        let packaging = coordinates.packaging.as_str();
 
-        if let Some(classifier) = &self.classifier {
-            file_name += FILENAME_SPLITTER;
-            file_name += classifier;
+        if packaging != MAVEN_STANDARD_PACKAGING && !allowed.contains(&packaging) {
+            return Err(CoordinatesError::DisallowedPackaging(packaging.to_string()));
         }
 
-        file_name
+        Ok(coordinates)
     }
 
-    /// Returns complete file name for this artifact.
+    /// Validates that these coordinates follow Maven naming rules.
     ///
-    /// returns: String
+    /// Validation is lenient, like Maven itself, and only rejects values that are clearly
+    /// malformed or would break path and URL resolution:
+    ///
+    /// - None of the components can be blank.
+    /// - Group ID must consist of non-empty dot-separated segments.
+    /// - Group ID and artifact ID cannot contain whitespace.
+    /// - None of the components can contain colons or slashes.
+    /// - POM cannot have classifier, since POMs cannot be classified.
+    ///
+    /// Validation is opt-in, [`new`][0] does not run it, while [`new_strict`][1] does.
+    ///
+    /// [0]: Coordinates::new
+    /// [1]: Coordinates::new_strict
+    ///
+    /// # Returns
+    ///
+    /// Result<(), CoordinatesError>
+    ///
+    /// If any of the components is empty or consists only of whitespace,
+    /// `Err(CoordinatesError::EmptyComponent)` naming the component will be returned. If any of
+    /// the components breaks other rules, `Err(CoordinatesError::MalformedComponent)` naming the
+    /// component will be returned. If classifier is set for `pom` packaging,
+    /// `Err(CoordinatesError::ClassifiedPom)` will be returned.
     ///
     /// # Examples
     ///
     /// ```
-    /// use maven_coordinates::Coordinates;
+    /// use maven_coordinates::{Coordinates, CoordinatesError};
     ///
-    /// Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT:jar:sources").unwrap().file_name();
-    /// // => "artifact-1.0.0-SNAPSHOT-sources.jar"
+    /// let artifact = Coordinates::new("io.github..brawaru:artifact:1.0.0").unwrap();
+    /// assert_eq!(artifact.validate(), Err(CoordinatesError::MalformedComponent("group ID")));
     /// ```
-    pub fn file_name(&self) -> String {
-        let mut file_name = self.file_basename().to_string();
+    pub fn validate(&self) -> Result<(), CoordinatesError> {
+        let components = [
+            ("group ID", Some(&self.group_id)),
+            ("artifact ID", Some(&self.artifact_id)),
+            ("version", Some(&self.version)),
+            ("version label", self.version_label.as_ref()),
+            ("build metadata", self.build_metadata.as_ref()),
+            ("packaging", Some(&self.packaging)),
+            ("classifier", self.classifier.as_ref()),
+        ];
 
-        file_name += EXTENSION_SPLITTER;
-        file_name += &self.packaging;
+        let blank = components
+            .iter()
+            .find(|(_, value)| value.is_some_and(|value| value.trim().is_empty()));
 
-        file_name
-    }
+        if let Some((component, _)) = blank {
+            return Err(CoordinatesError::EmptyComponent(component));
+        }
 
-    /// Converts coordinates to the path string with default separator (`/`).
-    ///
-    /// returns: String
-    pub fn to_path(&self) -> String {
-        self.as_path_with_separator(DEFAULT_SEPARATOR)
+        let has_whitespace = |value: &str| value.contains(char::is_whitespace);
+
+        if self
+            .group_id
+            .split(VERSION_SEGMENT_SPLITTER)
+            .any(str::is_empty)
+            || has_whitespace(&self.group_id)
+        {
+            return Err(CoordinatesError::MalformedComponent("group ID"));
+        }
+
+        if has_whitespace(&self.artifact_id) {
+            return Err(CoordinatesError::MalformedComponent("artifact ID"));
+        }
+
+        let malformed = components.iter().find(|(_, value)| {
+            value.is_some_and(|value| value.contains(&FORBIDDEN_CHARACTERS[..]))
+        });
+
+        if let Some((component, _)) = malformed {
+            return Err(CoordinatesError::MalformedComponent(component));
+        }
+
+        if self.packaging == POM_PACKAGING && self.classifier.is_some() {
+            return Err(CoordinatesError::ClassifiedPom);
+        }
+
+        Ok(())
     }
 
-    /// Converts coordinates to the path string with custom separator.
+    /// Creates new coordinates struct from the coordinates string, where components can be
+    /// wrapped in double quotes to include colons in them.
     ///
     /// # Arguments
     ///
-    /// * `separator`: path separator.
+    /// * `coordinates`: Maven coordinates string, which follows the format:
+    ///   `$groupId:$artifactId:$version[:$packaging[:$classifier]]`, where any component can be
+    ///   quoted, like `"1:0"`.
     ///
-    /// returns: String
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If coordinates string is correct and parsed, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned, `UnbalancedQuotes` if any quote is left
+    /// unclosed.
     ///
     /// # Examples
     ///
     /// ```
     /// use maven_coordinates::Coordinates;
-    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
-    /// artifact.as_path_with_separator('\\');
-    /// // => "io\\github\\brawaru\\artifact\\1.0.0-SNAPSHOT\\artifact-1.0.0-SNAPSHOT.jar"
+    ///
+    /// let artifact = Coordinates::new_quoted(r#"io.github.brawaru:artifact:"1:0":jar"#).unwrap();
+    /// artifact.version;
+    /// // => "1:0"
     /// ```
-    pub fn as_path_with_separator(&self, separator: char) -> String {
-        let mut path = String::new();
+    pub fn new_quoted(coordinates: &str) -> Result<Self, CoordinatesError> {
+        let mut parts = Vec::new();
+        let mut part = String::new();
+        let mut quoted = false;
 
-        for directory in self.group_id.split(".") {
-            path.push_str(directory);
-            path.push(separator);
+        for character in coordinates.chars() {
+            match character {
+                '"' => quoted = !quoted,
+                ':' if !quoted => parts.push(std::mem::take(&mut part)),
+                _ => part.push(character),
+            }
         }
 
-        path.push_str(&self.artifact_id);
-        path.push(separator);
+        if quoted {
+            return Err(CoordinatesError::UnbalancedQuotes(coordinates.to_string()));
+        }
 
-        path.push_str(self.full_version().as_str());
-        path.push(separator);
+        parts.push(part);
+
+        if parts.len() > 5 {
+            return Err(CoordinatesError::TooManyComponents(coordinates.to_string()));
+        }
 
-        path.push_str(self.file_name().as_str());
+        let mut parts = parts.iter().map(String::as_str);
 
-        path
+        Coordinates::from_required_parts(
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        )
     }
 
-    /// Resolves URL for the artifact using given base Maven server address.
+    /// Creates new coordinates struct from the coordinates string, defensively fixing classifier
+    /// and packaging written in the wrong order.
+    ///
+    /// Some tools emit `$groupId:$artifactId:$version:$classifier:$packaging`. When fourth
+    /// component is a well-known classifier (like `sources`) and fifth is a well-known packaging
+    /// (like `jar`), they are swapped back. Otherwise coordinates are parsed as by [`new`][0].
+    ///
+    /// This is a heuristic: it only recognizes a fixed list of common classifiers and packagings,
+    /// so swapped custom values are left as-is, and a legitimate classifier that happens to match
+    /// a known packaging name (e.g. `zip`) is never swapped.
+    ///
+    /// [0]: Coordinates::new
     ///
     /// # Arguments
     ///
-    /// * `maven_server`: Address of remote Maven server
+    /// * `coordinates`: Maven coordinates string.
     ///
-    /// returns: String
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If coordinates string is correct and parsed, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
     ///
     /// # Examples
     ///
     /// ```
     /// use maven_coordinates::Coordinates;
     ///
-    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
-    /// coords.resolve("https://brawaru.github.io/maven/");
-    /// // => "https://brawaru.github.io/maven/io/github/brawaru/artifact/1.0.0-SNAPSHOT/artifact-1.0.0-SNAPSHOT.jar"
+    /// let artifact = Coordinates::new_lenient_swap("io.github.brawaru:artifact:1.0.0:sources:jar").unwrap();
+    /// artifact.to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0:jar:sources"
     /// ```
-    pub fn resolve(&self, maven_location: &str) -> String {
-        let mut maven_location = maven_location.to_string();
+    pub fn new_lenient_swap(coordinates: &str) -> Result<Self, CoordinatesError> {
+        let mut result = Coordinates::new(coordinates)?;
 
-        if maven_location.chars().last().unwrap_or(' ') != '/' {
-            maven_location += "/";
-        }
+        let is_swapped = match &result.classifier {
+            Some(classifier) => {
+                KNOWN_CLASSIFIERS.contains(&result.packaging.as_str())
+                    && KNOWN_PACKAGINGS.contains(&classifier.as_str())
+            }
+            None => false,
+        };
 
-        maven_location += &self.to_path();
+        if is_swapped {
+            let packaging = result.classifier.take().unwrap_or_default();
+            result.classifier = Some(std::mem::replace(&mut result.packaging, packaging));
+        }
 
-        maven_location
+        Ok(result)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::Coordinates;
-    use std::io::ErrorKind;
-
-    #[test]
+    /// Creates new coordinates struct from the coordinates string where version goes last.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Maven coordinates string, which follows the format:
+    ///   `$groupId:$artifactId[:$packaging[:$classifier]]:$version`.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If coordinates string is correct and parsed, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new_version_last("io.github.brawaru:artifact:jar:sources:1.0.0").unwrap();
+    /// ```
+    pub fn new_version_last(coordinates: &str) -> Result<Self, CoordinatesError> {
+        let parts: Vec<&str> = coordinates.split(MAVEN_COORDINATES_SPLITTER).collect();
+
+        match parts.as_slice() {
+            [group_id, artifact_id, version] => Coordinates::from_required_parts(
+                Some(group_id),
+                Some(artifact_id),
+                Some(version),
+                None,
+                None,
+            ),
+            [group_id, artifact_id, packaging, version] => Coordinates::from_required_parts(
+                Some(group_id),
+                Some(artifact_id),
+                Some(version),
+                Some(packaging),
+                None,
+            ),
+            [group_id, artifact_id, packaging, classifier, version] => {
+                Coordinates::from_required_parts(
+                    Some(group_id),
+                    Some(artifact_id),
+                    Some(version),
+                    Some(packaging),
+                    Some(classifier),
+                )
+            }
+            [_] => Err(CoordinatesError::MissingArtifactId),
+            [_, _] => Err(CoordinatesError::MissingVersion),
+            _ => Err(CoordinatesError::TooManyComponents(coordinates.to_string())),
+        }
+    }
+
+    /// Checks whether the file name remainder following the version starts where version ends,
+    /// that is with a dash or with a dot that is not followed by another numeric version segment
+    /// (like `.1` in `1.0.1` following `1.0`).
+    ///
+    /// # Arguments
+    ///
+    /// * `rest`: File name following the version, like `-sources.jar` or `.jar`.
+    ///
+    /// returns: bool
+    fn is_version_boundary(rest: &str) -> bool {
+        if rest.starts_with(FILENAME_SPLITTER) {
+            return true;
+        }
+
+        let rest = match rest.strip_prefix(EXTENSION_SPLITTER) {
+            Some(rest) => rest,
+            None => return false,
+        };
+
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let is_version_segment =
+            digits > 0 && matches!(rest.as_bytes().get(digits), None | Some(b'.') | Some(b'-'));
+
+        !is_version_segment
+    }
+
+    /// Splits unique (timestamped) snapshot version from the start of the file name remainder,
+    /// for the `SNAPSHOT` version directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `rest`: File name following the artifact ID and the dash, like
+    ///   `1.0.0-20231001.123456-3-sources.jar`.
+    /// * `version`: Version of the directory, like `1.0.0-SNAPSHOT`.
+    ///
+    /// returns: `Option<(&str, &str)>`
+    fn split_unique_snapshot_version<'a>(
+        rest: &'a str,
+        version: &str,
+    ) -> Option<(&'a str, &'a str)> {
+        let version_stem = version.strip_suffix(SNAPSHOT_LABEL)?;
+        let suffix = rest.strip_prefix(version_stem)?;
+
+        // yyyyMMdd.HHmmss-$buildNumber
+        let build_number_index = "yyyyMMdd.HHmmss-".len();
+        let build_number = suffix.get(build_number_index..)?;
+        let end_index = build_number
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(build_number.len())
+            + build_number_index;
+
+        Coordinates::parse_snapshot_timestamp(&suffix[..end_index])?;
+
+        let split_index = version_stem.len() + end_index;
+
+        Some((&rest[..split_index], &rest[split_index..]))
+    }
+
+    /// Creates new coordinates struct from the Eclipse Aether artifact string, as produced by
+    /// `DefaultArtifact`.
+    ///
+    /// Aether uses the same layout as [`new_version_last`][0], but extension is always considered
+    /// explicit when present.
+    ///
+    /// [0]: Coordinates::new_version_last
+    ///
+    /// # Arguments
+    ///
+    /// * `artifact`: Aether artifact string, which follows the format:
+    ///   `$groupId:$artifactId[:$extension[:$classifier]]:$version`.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If artifact string is correct and parsed, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_aether("io.github.brawaru:artifact:zip:natives:1.0.0").unwrap();
+    /// artifact.to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0:zip:natives"
+    /// ```
+    pub fn from_aether(artifact: &str) -> Result<Self, CoordinatesError> {
+        let mut coordinates = Coordinates::new_version_last(artifact)?;
+        coordinates.explicit_packaging = artifact.split(MAVEN_COORDINATES_SPLITTER).count() > 3;
+
+        Ok(coordinates)
+    }
+
+    /// Creates new coordinates struct from the contents of `pom.properties` file embedded in JARs.
+    ///
+    /// Only `groupId`, `artifactId` and `version` properties are read, the rest are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `text`: Contents of the `META-INF/maven/$groupId/$artifactId/pom.properties` file.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If all three properties are present, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` naming the missing property will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let properties = "groupId=io.github.brawaru\nartifactId=artifact\nversion=1.0.0\n";
+    /// let artifact = Coordinates::from_pom_properties(properties).unwrap();
+    /// ```
+    pub fn from_pom_properties(text: &str) -> Result<Self, CoordinatesError> {
+        let mut group_id = None;
+        let mut artifact_id = None;
+        let mut version = None;
+
+        for line in text.lines().map(str::trim) {
+            if line.starts_with(PROPERTIES_COMMENTS) {
+                continue;
+            }
+
+            if let Some(split_index) = line.find(PROPERTIES_SPLITTER) {
+                let value = line[split_index + 1..].trim();
+
+                match line[..split_index].trim() {
+                    "groupId" => group_id = Some(value),
+                    "artifactId" => artifact_id = Some(value),
+                    "version" => version = Some(value),
+                    _ => {}
+                }
+            }
+        }
+
+        Coordinates::from_required_parts(group_id, artifact_id, version, None, None)
+    }
+
+    /// Creates new coordinates struct from the dependency declaration in Gradle Kotlin DSL.
+    ///
+    /// Both string notation (`implementation("$groupId:$artifactId:$version")`) and named
+    /// arguments notation (`implementation(group = "...", name = "...", version = "...")`) are
+    /// supported. Named arguments notation also accepts `classifier` and `ext` arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `line`: Dependency declaration line.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If declaration is correct and parsed, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_kotlin_dsl(r#"implementation("io.github.brawaru:artifact:1.0.0")"#).unwrap();
+    /// ```
+    pub fn from_kotlin_dsl(line: &str) -> Result<Self, CoordinatesError> {
+        let invalid = || CoordinatesError::InvalidNotation(line.to_string());
+
+        let arguments = match (line.find('('), line.rfind(')')) {
+            (Some(start_index), Some(end_index)) if start_index < end_index => {
+                line[start_index + 1..end_index].trim()
+            }
+            _ => return Err(invalid()),
+        };
+
+        if arguments.starts_with('"') {
+            let notation = Coordinates::unquote(arguments).ok_or_else(invalid)?;
+
+            return Coordinates::new(notation);
+        }
+
+        let mut group_id = None;
+        let mut artifact_id = None;
+        let mut version = None;
+        let mut packaging = None;
+        let mut classifier = None;
+
+        for argument in arguments.split(',') {
+            let split_index = argument.find('=').ok_or_else(invalid)?;
+            let value =
+                Coordinates::unquote(argument[split_index + 1..].trim()).ok_or_else(invalid)?;
+
+            match argument[..split_index].trim() {
+                "group" => group_id = Some(value),
+                "name" => artifact_id = Some(value),
+                "version" => version = Some(value),
+                "ext" => packaging = Some(value),
+                "classifier" => classifier = Some(value),
+                _ => return Err(invalid()),
+            }
+        }
+
+        Coordinates::from_required_parts(group_id, artifact_id, version, packaging, classifier)
+    }
+
+    /// Creates new coordinates struct from the Gradle dependency notation.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation`: Gradle dependency notation, which follows the format:
+    ///   `$group:$name:$version[:$classifier][@$extension]`, where extension overrides the
+    ///   packaging.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If notation is correct and parsed, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_gradle("io.github.brawaru:artifact:1.0.0:natives@zip").unwrap();
+    /// artifact.to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0:zip:natives"
+    /// ```
+    pub fn from_gradle(notation: &str) -> Result<Self, CoordinatesError> {
+        let (coordinates, extension) = match notation.rfind(GRADLE_EXTENSION_SPLITTER) {
+            Some(split_index) => (&notation[..split_index], Some(&notation[split_index + 1..])),
+            None => (notation, None),
+        };
+
+        if extension == Some("") {
+            return Err(CoordinatesError::EmptyComponent("packaging"));
+        }
+
+        let parts: Vec<&str> = coordinates.split(MAVEN_COORDINATES_SPLITTER).collect();
+
+        if parts.len() > 4 {
+            return Err(CoordinatesError::TooManyComponents(notation.to_string()));
+        }
+
+        let mut parts = parts.into_iter();
+        let (group_id, artifact_id, version) = (parts.next(), parts.next(), parts.next());
+
+        let mut coordinates = Coordinates::from_required_parts(
+            group_id,
+            artifact_id,
+            version,
+            extension,
+            parts.next(),
+        )?;
+        coordinates.explicit_packaging = extension.is_some();
+
+        Ok(coordinates)
+    }
+
+    /// Creates new coordinates struct from the `name@version` notation used by polyglot tools,
+    /// where name is `$groupId:$artifactId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation`: Notation, which follows the format: `$groupId:$artifactId@$version`.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If notation is correct and parsed, this will be `Ok(Coordinates)`. Otherwise
+    /// `Err(CoordinatesError)` will be returned, `MissingVersion` if there is no `@`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_at_version("io.github.brawaru:artifact@1.0.0").unwrap();
+    /// artifact.to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0"
+    /// ```
+    pub fn from_at_version(notation: &str) -> Result<Self, CoordinatesError> {
+        let (name, version) = match notation.rfind(AT_VERSION_SPLITTER) {
+            Some(split_index) => (&notation[..split_index], &notation[split_index + 1..]),
+            None => return Err(CoordinatesError::MissingVersion),
+        };
+
+        let mut parts = name.split(MAVEN_COORDINATES_SPLITTER);
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (group_id, artifact_id, None) => {
+                Coordinates::from_required_parts(group_id, artifact_id, Some(version), None, None)
+            }
+            _ => Err(CoordinatesError::TooManyComponents(notation.to_string())),
+        }
+    }
+
+    /// Removes double quotes surrounding the string literal.
+    ///
+    /// # Arguments
+    ///
+    /// * `literal`: String literal in double quotes.
+    ///
+    /// returns: `Option<&str>`
+    fn unquote(literal: &str) -> Option<&str> {
+        let literal = literal.strip_prefix('"')?;
+        let end_index = literal.find('"')?;
+
+        Some(&literal[..end_index])
+    }
+
+    /// Creates new coordinates struct from the line of `mvn dependency:tree` output.
+    ///
+    /// Maven lists dependencies in the tree as
+    /// `$groupId:$artifactId:$packaging[:$classifier]:$version[:$scope]`, where scope is omitted
+    /// for the root artifact. Tree glyphs, `[INFO]` prefix and the annotations following the
+    /// coordinates (like `(optional)`) are ignored, as well as the scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `line`: Line of the dependency tree.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If line contains correct coordinates, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_dependency_tree_line("|  +- io.github.brawaru:artifact:jar:1.0.0:compile").unwrap();
+    /// ```
+    pub fn from_dependency_tree_line(line: &str) -> Result<Self, CoordinatesError> {
+        let line = line.trim_start();
+        let line = line.strip_prefix(MAVEN_LOG_PREFIX).unwrap_or(line);
+        let line = line.trim_start_matches(&DEPENDENCY_TREE_GLYPHS[..]);
+
+        let notation = line.split_whitespace().next().unwrap_or("");
+        let (coordinates, _scope) = Coordinates::parse_dependency_notation(notation)?;
+
+        Ok(coordinates)
+    }
+
+    /// Creates new dependency from the line of `mvn dependency:list` output.
+    ///
+    /// Maven lists dependencies as
+    /// `$groupId:$artifactId:$packaging[:$classifier]:$version:$scope`. The `[INFO]` prefix and
+    /// the annotations following the coordinates (like `-- module lib`) are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `line`: Line of the dependency list.
+    ///
+    /// # Returns
+    ///
+    /// Result<Dependency, CoordinatesError>
+    ///
+    /// If line contains correct coordinates with scope, this will be `Ok(Dependency)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let dependency = Coordinates::from_dependency_list_line("[INFO]    io.github.brawaru:artifact:jar:1.0.0:test").unwrap();
+    /// dependency.scope;
+    /// // => Some("test")
+    /// ```
+    pub fn from_dependency_list_line(line: &str) -> Result<Dependency, CoordinatesError> {
+        let line = line.trim_start();
+        let line = line.strip_prefix(MAVEN_LOG_PREFIX).unwrap_or(line);
+
+        let notation = line.split_whitespace().next().unwrap_or("");
+
+        match Coordinates::parse_dependency_notation(notation)? {
+            (coordinates, Some(scope)) => {
+                let mut dependency = Dependency::new(coordinates);
+                dependency.scope = Some(scope.to_string());
+
+                Ok(dependency)
+            }
+            (_, None) => Err(CoordinatesError::InvalidNotation(notation.to_string())),
+        }
+    }
+
+    /// Parses dependency notation used in Maven Dependency Plugin output, which follows the
+    /// format: `$groupId:$artifactId:$packaging[:$classifier]:$version[:$scope]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation`: Dependency notation.
+    ///
+    /// returns: `Result<(Coordinates, Option<&str>), CoordinatesError>`
+    fn parse_dependency_notation(notation: &str) -> Result<(Self, Option<&str>), CoordinatesError> {
+        let parts: Vec<&str> = notation.split(MAVEN_COORDINATES_SPLITTER).collect();
+
+        match parts.as_slice() {
+            [group_id, artifact_id, packaging, version] => Ok((
+                Coordinates::from_parts(group_id, artifact_id, version, Some(packaging), None),
+                None,
+            )),
+            [group_id, artifact_id, packaging, version, scope] => Ok((
+                Coordinates::from_parts(group_id, artifact_id, version, Some(packaging), None),
+                Some(scope),
+            )),
+            [group_id, artifact_id, packaging, classifier, version, scope] => Ok((
+                Coordinates::from_parts(
+                    group_id,
+                    artifact_id,
+                    version,
+                    Some(packaging),
+                    Some(classifier),
+                ),
+                Some(scope),
+            )),
+            _ => Err(CoordinatesError::InvalidNotation(notation.to_string())),
+        }
+    }
+
+    /// Creates new coordinates struct from the SPDX external reference locator of Maven package.
+    ///
+    /// Locator is expected to contain [package URL][purl] of the Maven package, like
+    /// `pkg:maven/$groupId/$artifactId@$version?classifier=$classifier&type=$packaging`. Both
+    /// bare locators and complete `ExternalRef` tag-value lines are accepted.
+    ///
+    /// [purl]: https://github.com/package-url/purl-spec
+    ///
+    /// # Arguments
+    ///
+    /// * `ref_locator`: External reference locator.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If locator contains correct Maven package URL, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_spdx_external_ref("pkg:maven/io.github.brawaru/artifact@1.0.0").unwrap();
+    /// ```
+    pub fn from_spdx_external_ref(ref_locator: &str) -> Result<Self, CoordinatesError> {
+        let purl = ref_locator
+            .split_whitespace()
+            .find(|part| part.starts_with(MAVEN_PURL_PREFIX))
+            .ok_or_else(|| CoordinatesError::InvalidNotation(ref_locator.to_string()))?;
+
+        Coordinates::from_purl(purl)
+    }
+
+    /// Creates new coordinates struct from the package URL of Maven package.
+    ///
+    /// # Arguments
+    ///
+    /// * `purl`: Package URL, which follows the format:
+    ///   `pkg:maven/$groupId/$artifactId@$version[?$qualifiers][#$subpath]`.
+    ///
+    /// returns: `Result<Coordinates, CoordinatesError>`
+    fn from_purl(purl: &str) -> Result<Self, CoordinatesError> {
+        let invalid = || CoordinatesError::InvalidNotation(purl.to_string());
+
+        let purl = purl.strip_prefix(MAVEN_PURL_PREFIX).ok_or_else(invalid)?;
+        let purl = purl.split('#').next().unwrap_or(purl);
+
+        let (purl, qualifiers) = match purl.find('?') {
+            Some(split_index) => (&purl[..split_index], &purl[split_index + 1..]),
+            None => (purl, ""),
+        };
+
+        let version_index = purl.rfind('@').ok_or_else(invalid)?;
+        let (name, version) = (&purl[..version_index], &purl[version_index + 1..]);
+
+        let name_index = name.rfind('/').ok_or_else(invalid)?;
+        let (group_id, artifact_id) = (&name[..name_index], &name[name_index + 1..]);
+
+        let mut packaging = None;
+        let mut classifier = None;
+
+        for qualifier in qualifiers.split('&') {
+            if let Some(split_index) = qualifier.find('=') {
+                let value = percent_decode(&qualifier[split_index + 1..]).ok_or_else(invalid)?;
+
+                match &qualifier[..split_index] {
+                    "type" => packaging = Some(value),
+                    "classifier" => classifier = Some(value),
+                    _ => {}
+                }
+            }
+        }
+
+        Coordinates::from_required_parts(
+            Some(&percent_decode(group_id).ok_or_else(invalid)?),
+            Some(&percent_decode(artifact_id).ok_or_else(invalid)?),
+            Some(&percent_decode(version).ok_or_else(invalid)?),
+            packaging.as_deref(),
+            classifier.as_deref(),
+        )
+    }
+
+    /// Creates new coordinates struct from the Coursier dependency notation.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation`: Coursier dependency notation, which follows the format:
+    ///   `$groupId:$artifactId:$version[,classifier=$classifier][,type=$packaging]`. Other
+    ///   attributes (like `exclude` or `url`) are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If notation is correct and parsed, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_coursier("io.github.brawaru:artifact:1.0.0,classifier=sources").unwrap();
+    /// ```
+    pub fn from_coursier(notation: &str) -> Result<Self, CoordinatesError> {
+        let invalid = || CoordinatesError::InvalidNotation(notation.to_string());
+
+        let mut attributes = notation.split(COURSIER_ATTRIBUTES_SPLITTER);
+        let coordinates = attributes.next().unwrap_or("");
+
+        let mut parts = coordinates.split(MAVEN_COORDINATES_SPLITTER);
+
+        let (group_id, artifact_id, version) =
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (group_id, artifact_id, version, None) => (group_id, artifact_id, version),
+                _ => return Err(invalid()),
+            };
+
+        let mut packaging = None;
+        let mut classifier = None;
+
+        for attribute in attributes {
+            let split_index = attribute.find('=').ok_or_else(invalid)?;
+            let value = &attribute[split_index + 1..];
+
+            match &attribute[..split_index] {
+                "type" => packaging = Some(value),
+                "classifier" => classifier = Some(value),
+                _ => {}
+            }
+        }
+
+        Coordinates::from_required_parts(group_id, artifact_id, version, packaging, classifier)
+    }
+
+    /// Creates new coordinates struct from the map of Maven field names to their values, the
+    /// inverse of [`to_field_map`][0].
+    ///
+    /// [0]: Coordinates::to_field_map
+    ///
+    /// # Arguments
+    ///
+    /// * `map`: Map with `groupId`, `artifactId`, `version` and optional `type` and `classifier`
+    ///   keys. Other keys are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If all the mandatory keys are present, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError)` naming the missing key will be returned.
+    pub fn from_mapping(map: &BTreeMap<String, String>) -> Result<Self, CoordinatesError> {
+        let field = |name: &str| map.get(name).map(String::as_str);
+
+        Coordinates::from_required_parts(
+            field(FIELD_GROUP_ID),
+            field(FIELD_ARTIFACT_ID),
+            field(FIELD_VERSION),
+            field(FIELD_TYPE),
+            field(FIELD_CLASSIFIER),
+        )
+    }
+
+    /// Creates new coordinates struct from the JSON object with custom names of the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `json`: JSON object, like `{"g": "...", "a": "...", "v": "..."}`. Other keys are
+    ///   ignored.
+    /// * `group_key`: Key of the group ID.
+    /// * `artifact_key`: Key of the artifact ID.
+    /// * `version_key`: Key of the version.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If JSON is an object with all the keys set to strings, this will be `Ok(Coordinates)`.
+    /// Otherwise `Err(CoordinatesError)` naming the missing key will be returned, or
+    /// `Err(CoordinatesError::InvalidNotation)` if JSON is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let json = r#"{"g": "io.github.brawaru", "a": "artifact", "v": "1.0.0"}"#;
+    /// let artifact = Coordinates::from_json_with_keys(json, "g", "a", "v").unwrap();
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn from_json_with_keys(
+        json: &str,
+        group_key: &str,
+        artifact_key: &str,
+        version_key: &str,
+    ) -> Result<Self, CoordinatesError> {
+        let value: serde_json::Value = serde_json::from_str(json)
+            .map_err(|_| CoordinatesError::InvalidNotation(json.to_string()))?;
+        let object = value
+            .as_object()
+            .ok_or_else(|| CoordinatesError::InvalidNotation(json.to_string()))?;
+
+        let field = |key: &str| object.get(key).and_then(serde_json::Value::as_str);
+
+        Coordinates::from_required_parts(
+            field(group_key),
+            field(artifact_key),
+            field(version_key),
+            None,
+            None,
+        )
+    }
+
+    /// Creates new coordinates struct from the path of the artifact under the repository root,
+    /// like `C:\repo\io\github\brawaru\artifact\1.0.0\artifact-1.0.0.jar`, see
+    /// [`from_path`][0].
+    ///
+    /// Root is stripped before parsing, so that drive letter and directories of the root do not
+    /// become group ID segments.
+    ///
+    /// [0]: Coordinates::from_path
+    ///
+    /// # Arguments
+    ///
+    /// * `root`: Path of the repository root, like `C:\repo`. Trailing separator is optional.
+    /// * `path`: Path of the artifact, starting with the root.
+    /// * `separator`: Separator used in both paths.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If path is under the root and the rest of it follows the repository layout, this will be
+    /// `Ok(Coordinates)`, otherwise `Err(CoordinatesError::InvalidNotation)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let path = r"C:\repo\io\github\brawaru\artifact\1.0.0\artifact-1.0.0.jar";
+    /// let artifact = Coordinates::from_path_under(r"C:\repo\", path, '\\').unwrap();
+    /// artifact.to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0"
+    /// ```
+    pub fn from_path_under(
+        root: &str,
+        path: &str,
+        separator: char,
+    ) -> Result<Self, CoordinatesError> {
+        let root = root.trim_end_matches(separator);
+
+        let relative_path = path
+            .strip_prefix(root)
+            .filter(|rest| rest.starts_with(separator))
+            .ok_or_else(|| CoordinatesError::InvalidNotation(path.to_string()))?;
+
+        Coordinates::from_path(relative_path, separator)
+    }
+
+    /// Creates new coordinates struct from the path of the artifact in the repository, the
+    /// inverse of [`as_path_with_separator`][0].
+    ///
+    /// Path is split into the group ID directories, artifact ID directory, version directory and
+    /// the file name. File name must start with the artifact ID and the complete version from the
+    /// version directory, which are followed by optional classifier and the packaging, which is
+    /// everything after the first dot following them (like `jar.sha1`).
+    ///
+    /// In `SNAPSHOT` version directories unique (timestamped) snapshot files are accepted as well,
+    /// like `artifact-1.0.0-20231001.123456-3.jar` in `1.0.0-SNAPSHOT` directory, in which case
+    /// version is taken from the file name (`1.0.0-20231001.123456-3`).
+    ///
+    /// [0]: Coordinates::as_path_with_separator
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: Path of the artifact, like `io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar`.
+    ///   Leading and trailing separators are ignored.
+    /// * `separator`: Separator used in the path.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If path follows the repository layout, this will be `Ok(Coordinates)`, otherwise
+    /// `Err(CoordinatesError::InvalidNotation)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_path("io/github/brawaru/artifact/1.0.0-SNAPSHOT/artifact-1.0.0-SNAPSHOT-sources.jar", '/').unwrap();
+    /// artifact.to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0-SNAPSHOT:jar:sources"
+    /// ```
+    pub fn from_path(path: &str, separator: char) -> Result<Self, CoordinatesError> {
+        let invalid = || CoordinatesError::InvalidNotation(path.to_string());
+
+        let parts: Vec<&str> = path.trim_matches(separator).split(separator).collect();
+
+        let (group_parts, artifact_id, version, file_name) = match parts.as_slice() {
+            [group_parts @ .., artifact_id, version, file_name] if !group_parts.is_empty() => {
+                (group_parts, *artifact_id, *version, *file_name)
+            }
+            _ => return Err(invalid()),
+        };
+
+        let rest = file_name
+            .strip_prefix(artifact_id)
+            .and_then(|rest| rest.strip_prefix(FILENAME_SPLITTER))
+            .ok_or_else(invalid)?;
+
+        let (version, rest) = match rest.strip_prefix(version) {
+            Some(rest) if Coordinates::is_version_boundary(rest) => (version, rest),
+            _ => Coordinates::split_unique_snapshot_version(rest, version).ok_or_else(invalid)?,
+        };
+
+        let (classifier, packaging) = match rest.find(EXTENSION_SPLITTER) {
+            Some(split_index) => (&rest[..split_index], &rest[split_index + 1..]),
+            None => return Err(invalid()),
+        };
+
+        let classifier = if classifier.is_empty() {
+            None
+        } else {
+            Some(
+                classifier
+                    .strip_prefix(FILENAME_SPLITTER)
+                    .ok_or_else(invalid)?,
+            )
+        };
+
+        if packaging.is_empty() {
+            return Err(invalid());
+        }
+
+        Coordinates::from_required_parts(
+            Some(&group_parts.join(VERSION_SEGMENT_SPLITTER)),
+            Some(artifact_id),
+            Some(version),
+            Some(packaging),
+            classifier,
+        )
+    }
+
+    /// Creates new coordinates struct from the artifact file name and the group ID.
+    ///
+    /// File name is expected to follow the format
+    /// `$artifactId-$version[-$label][-$classifier].$packaging`. Since dashes can appear in all of
+    /// the components, they are disambiguated as follows:
+    ///
+    /// 1. Packaging is everything after the last dot, also including the preceding extension
+    ///    for checksum and signature files (like `jar.sha1`) and compressed tarballs (like
+    ///    `tar.gz`).
+    /// 2. Version is the first dash-separated segment starting with a digit, everything before
+    ///    it is the artifact ID.
+    /// 3. Following segments that start with a digit or are well-known qualifiers (like
+    ///    `SNAPSHOT`, `alpha` or `rc1`) are kept in the version.
+    /// 4. Whatever trailing segments remain are the classifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name`: File name of the artifact, like `artifact-1.0.0-sources.jar`.
+    /// * `group_id`: Group ID of the artifact.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If file name is parsed, this will be `Ok(Coordinates)`. Otherwise `Err(CoordinatesError)`
+    /// will be returned: `MissingArtifactId` or `MissingVersion` if file name does not contain
+    /// them, `InvalidNotation` if it has no extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::from_file_name("jackson-core-2.15.2-sources.jar", "com.fasterxml.jackson.core").unwrap();
+    /// artifact.to_string();
+    /// // => "com.fasterxml.jackson.core:jackson-core:2.15.2:jar:sources"
+    /// ```
+    pub fn from_file_name(file_name: &str, group_id: &str) -> Result<Self, CoordinatesError> {
+        let (stem, packaging) = Coordinates::split_file_extension(file_name)
+            .ok_or_else(|| CoordinatesError::InvalidNotation(file_name.to_string()))?;
+
+        let segments: Vec<&str> = stem.split(FILENAME_SPLITTER).collect();
+
+        let starts_with_digit = |segment: &str| segment.starts_with(|c: char| c.is_ascii_digit());
+
+        let version_index = segments
+            .iter()
+            .position(|segment| starts_with_digit(segment))
+            .ok_or(CoordinatesError::MissingVersion)?;
+
+        if version_index == 0 {
+            return Err(CoordinatesError::MissingArtifactId);
+        }
+
+        let is_qualifier = |segment: &str| {
+            let word = segment
+                .trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+                .to_ascii_lowercase();
+
+            starts_with_digit(segment) || KNOWN_QUALIFIERS.contains(&word.as_str())
+        };
+
+        let classifier_index = segments[version_index + 1..]
+            .iter()
+            .position(|segment| !is_qualifier(segment))
+            .map_or(segments.len(), |index| version_index + 1 + index);
+
+        let classifier = segments[classifier_index..].join(FILENAME_SPLITTER);
+
+        Coordinates::from_required_parts(
+            Some(group_id),
+            Some(&segments[..version_index].join(FILENAME_SPLITTER)),
+            Some(&segments[version_index..classifier_index].join(FILENAME_SPLITTER)),
+            Some(packaging),
+            Some(classifier.as_str()).filter(|classifier| !classifier.is_empty()),
+        )
+    }
+
+    /// Splits file name into the slices of the name itself and the extension, keeping multi-part
+    /// extensions (like `tar.gz` or `jar.sha1`) together.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name`: File name to split.
+    ///
+    /// returns: `Option<(&str, &str)>`
+    fn split_file_extension(file_name: &str) -> Option<(&str, &str)> {
+        let mut split_index = file_name.rfind(EXTENSION_SPLITTER)?;
+
+        if Coordinates::is_sidecar_extension(&file_name[split_index + 1..]) {
+            split_index = file_name[..split_index].rfind(EXTENSION_SPLITTER)?;
+        }
+
+        let extension = file_name[split_index + 1..]
+            .split(EXTENSION_SPLITTER)
+            .next()
+            .unwrap_or_default();
+
+        if COMPRESSION_EXTENSIONS.contains(&extension) {
+            let tar_stem = file_name[..split_index]
+                .strip_suffix(TAR_EXTENSION)
+                .and_then(|stem| stem.strip_suffix(EXTENSION_SPLITTER));
+
+            if let Some(tar_stem) = tar_stem {
+                split_index = tar_stem.len();
+            }
+        }
+
+        Some((&file_name[..split_index], &file_name[split_index + 1..]))
+    }
+
+    /// Checks whether extension is the one of checksum or signature files published alongside
+    /// artifacts.
+    ///
+    /// # Arguments
+    ///
+    /// * `extension`: Extension without leading dot.
+    ///
+    /// returns: bool
+    fn is_sidecar_extension(extension: &str) -> bool {
+        extension == SIGNATURE_EXTENSION
+            || GRADLE_CHECKSUMS
+                .iter()
+                .any(|algorithm| algorithm.extension() == extension)
+    }
+
+    /// Creates coordinates struct from the separate parts, checking that group ID, artifact ID
+    /// and version are present and not empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id`: Group ID.
+    /// * `artifact_id`: Artifact ID.
+    /// * `version`: Complete version, which will be split into version and label.
+    /// * `packaging`: Packaging, standard packaging is used if `None`.
+    /// * `classifier`: Classifier.
+    ///
+    /// returns: `Result<Coordinates, CoordinatesError>`
+    fn from_required_parts(
+        group_id: Option<&str>,
+        artifact_id: Option<&str>,
+        version: Option<&str>,
+        packaging: Option<&str>,
+        classifier: Option<&str>,
+    ) -> Result<Self, CoordinatesError> {
+        let group_id = group_id.ok_or(CoordinatesError::MissingGroupId)?;
+        let artifact_id = artifact_id.ok_or(CoordinatesError::MissingArtifactId)?;
+        let version = version.ok_or(CoordinatesError::MissingVersion)?;
+
+        let components = [
+            ("group ID", group_id),
+            ("artifact ID", artifact_id),
+            ("version", version),
+        ];
+
+        if let Some((component, _)) = components.iter().find(|(_, value)| value.is_empty()) {
+            return Err(CoordinatesError::EmptyComponent(component));
+        }
+
+        Ok(Coordinates::from_parts(
+            group_id,
+            artifact_id,
+            version,
+            packaging,
+            classifier,
+        ))
+    }
+
+    /// Creates coordinates struct from the separate parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id`: Group ID.
+    /// * `artifact_id`: Artifact ID.
+    /// * `version`: Complete version, which will be split into version and label.
+    /// * `packaging`: Packaging, standard packaging is used if `None`.
+    /// * `classifier`: Classifier.
+    ///
+    /// returns: Coordinates
+    fn from_parts(
+        group_id: &str,
+        artifact_id: &str,
+        version: &str,
+        packaging: Option<&str>,
+        classifier: Option<&str>,
+    ) -> Self {
+        let (version, build_metadata) = Coordinates::split_build_metadata(version);
+        let (version, version_qualifier) = Coordinates::split_version(version);
+
+        Self {
+            group_id: group_id.to_string(),
+            artifact_id: artifact_id.to_string(),
+            version: version.to_string(),
+            version_label: version_qualifier.map(|q| q.to_string()),
+            build_metadata: build_metadata.map(|m| m.to_string()),
+            packaging: packaging.unwrap_or(MAVEN_STANDARD_PACKAGING).to_string(),
+            classifier: classifier.map(|s| s.to_string()),
+            explicit_packaging: false,
+        }
+    }
+
+    /// Splits version into the slices of version itself and the build metadata part.
+    ///
+    /// # Arguments
+    ///
+    /// * `version`: Source version string to split
+    ///
+    /// returns: (&str, Option<&str>)
+    fn split_build_metadata(version: &str) -> (&str, Option<&str>) {
+        if let Some(split_index) = version.find(BUILD_METADATA_SPLITTER) {
+            (&version[..split_index], Some(&version[split_index + 1..]))
+        } else {
+            (version, None)
+        }
+    }
+
+    /// Splits version into the slices of version itself and the qualifier part.
+    ///
+    /// Version is split at the first dash, so compound qualifiers like `alpha-1` are kept
+    /// together. Versions produced by `git describe` are never split, see
+    /// [`is_git_describe`][0].
+    ///
+    /// [0]: Coordinates::is_git_describe
+    ///
+    /// # Arguments
+    ///
+    /// * `version`: Source version string to split
+    ///
+    /// returns: (&str, Option<&str>)
+    fn split_version(version: &str) -> (&str, Option<&str>) {
+        if Coordinates::is_git_describe(version) {
+            return (version, None);
+        }
+
+        if let Some(split_index) = version.find(FILENAME_SPLITTER) {
+            (&version[..split_index], Some(&version[split_index + 1..]))
+        } else {
+            (version, None)
+        }
+    }
+
+    /// Checks whether version is produced by `git describe`, like `1.0-5-gabc123`.
+    ///
+    /// Such versions are recognized by the trailing `-$commits-g$hash` part, where `$commits` is
+    /// the number of commits since the tag and `$hash` is the abbreviated commit hash in lower-case
+    /// hexadecimal digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `version`: Version to check.
+    ///
+    /// returns: bool
+    fn is_git_describe(version: &str) -> bool {
+        let mut parts = version.rsplitn(3, FILENAME_SPLITTER);
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(hash), Some(commits), Some(tag)) => {
+                let hash = match hash.strip_prefix('g') {
+                    Some(hash) => hash,
+                    None => return false,
+                };
+
+                !tag.is_empty()
+                    && !commits.is_empty()
+                    && commits.bytes().all(|b| b.is_ascii_digit())
+                    && !hash.is_empty()
+                    && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns complete version (including the label and build metadata).
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap().full_version();
+    /// // => "1.0.0-SNAPSHOT"
+    /// ```
+    pub fn full_version(&self) -> String {
+        let mut full_version = self.version.to_string();
+
+        if let Some(version_qualifier) = &self.version_label {
+            full_version += FILENAME_SPLITTER;
+            full_version += version_qualifier;
+        }
+
+        if let Some(build_metadata) = &self.build_metadata {
+            full_version += BUILD_METADATA_SPLITTER;
+            full_version += build_metadata;
+        }
+
+        full_version
+    }
+
+    /// Checks whether these and other coordinates have the same version, ignoring the build
+    /// metadata as per semantic versioning rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: Coordinates to compare version with.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let build_5 = Coordinates::new("io.github.brawaru:artifact:1.0.0+build.5").unwrap();
+    /// let build_6 = Coordinates::new("io.github.brawaru:artifact:1.0.0+build.6").unwrap();
+    /// build_5.same_version(&build_6);
+    /// // => true
+    /// ```
+    pub fn same_version(&self, other: &Coordinates) -> bool {
+        self.version == other.version && self.version_label == other.version_label
+    }
+
+    /// Parses version (excluding the label) as semantic version triple of major, minor and patch.
+    ///
+    /// returns: `Option<(u64, u64, u64)>`
+    fn semver_triple(&self) -> Option<(u64, u64, u64)> {
+        match self.version_numbers().as_slice() {
+            [major, minor, patch] => Some((*major, *minor, *patch)),
+            _ => None,
+        }
+    }
+
+    /// Parses dot-separated numeric segments of the version, excluding the label.
+    ///
+    /// If any of the segments is not a number, no segments are returned.
+    ///
+    /// returns: `Vec<u64>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.2.3-SNAPSHOT").unwrap().version_numbers();
+    /// // => [1, 2, 3]
+    /// ```
+    pub fn version_numbers(&self) -> Vec<u64> {
+        self.version
+            .split(VERSION_SEGMENT_SPLITTER)
+            .map(str::parse)
+            .collect::<Result<Vec<u64>, _>>()
+            .unwrap_or_default()
+    }
+
+    /// Returns copy of these coordinates with patch number of the version incremented.
+    ///
+    /// Only versions shaped as semantic version (`major.minor.patch`, label is kept as-is) can be
+    /// bumped, for anything else `None` is returned. Build metadata is dropped, since it
+    /// describes the build of the original version.
+    ///
+    /// returns: `Option<Coordinates>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.2.3").unwrap().bump_patch();
+    /// // => Some(io.github.brawaru:artifact:1.2.4)
+    /// ```
+    pub fn bump_patch(&self) -> Option<Coordinates> {
+        let (major, minor, patch) = self.semver_triple()?;
+
+        let mut bumped = self.clone();
+        bumped.version = format!("{}.{}.{}", major, minor, patch + 1);
+        bumped.build_metadata = None;
+
+        Some(bumped)
+    }
+
+    /// Returns copies of these coordinates for every patch version from `major.minor.0` up to
+    /// `major.minor.up_to_patch` inclusive.
+    ///
+    /// Like with [`bump_patch`][0], only versions shaped as semantic version can be used, for
+    /// anything else series is empty. Label is kept as-is and build metadata is dropped.
+    ///
+    /// [0]: Coordinates::bump_patch
+    ///
+    /// # Arguments
+    ///
+    /// * `up_to_patch`: Last patch number of the series.
+    ///
+    /// returns: `Vec<Coordinates>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.2.5").unwrap().patch_series(2);
+    /// // => [io.github.brawaru:artifact:1.2.0, io.github.brawaru:artifact:1.2.1,
+    /// //     io.github.brawaru:artifact:1.2.2]
+    /// ```
+    pub fn patch_series(&self, up_to_patch: u32) -> Vec<Coordinates> {
+        let (major, minor) = match self.semver_triple() {
+            Some((major, minor, _)) => (major, minor),
+            None => return Vec::new(),
+        };
+
+        (0..=up_to_patch)
+            .map(|patch| {
+                let mut coordinates = self.clone();
+                coordinates.version = format!("{}.{}.{}", major, minor, patch);
+                coordinates.build_metadata = None;
+
+                coordinates
+            })
+            .collect()
+    }
+
+    /// Returns base file name for this artifact.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT:jar:sources").unwrap().file_basename();
+    /// // => "artifact-1.0.0-SNAPSHOT-sources"
+    /// ```
+    pub fn file_basename(&self) -> String {
+        let mut file_name = self.artifact_id.to_string();
+
+        file_name += FILENAME_SPLITTER;
+        file_name += &self.full_version();
+
+        if let Some(classifier) = &self.classifier {
+            file_name += FILENAME_SPLITTER;
+            file_name += classifier;
+        }
+
+        file_name
+    }
+
+    /// Returns complete file name for this artifact.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT:jar:sources").unwrap().file_name();
+    /// // => "artifact-1.0.0-SNAPSHOT-sources.jar"
+    /// ```
+    pub fn file_name(&self) -> String {
+        let mut file_name = self.file_basename().to_string();
+
+        file_name += EXTENSION_SPLITTER;
+        file_name += self.file_extension();
+
+        file_name
+    }
+
+    /// Returns extension of the artifact file.
+    ///
+    /// Extension is the same as packaging, except for packagings that are packaged as JARs
+    /// (`bundle`, `maven-plugin`, `ejb` and `test-jar`), which have `jar` extension.
+    ///
+    /// returns: &str
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:maven-plugin").unwrap().file_extension();
+    /// // => "jar"
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:war").unwrap().file_extension();
+    /// // => "war"
+    /// ```
+    pub fn file_extension(&self) -> &str {
+        self.file_extension_with_case(true)
+    }
+
+    /// Returns extension of the artifact file, see [`file_extension`][0], optionally recognizing
+    /// known packagings regardless of their case.
+    ///
+    /// By default (and in [`file_extension`][0]) packagings are recognized case-sensitively, so
+    /// `BUNDLE` is used as the extension as is. When case-insensitive, known packagings are
+    /// replaced with their canonical lower-case form, so `JAR` becomes `jar` and `BUNDLE` becomes
+    /// `jar` as well. Unknown packagings are always used as is.
+    ///
+    /// [0]: Coordinates::file_extension
+    ///
+    /// # Arguments
+    ///
+    /// * `case_sensitive`: Whether known packagings are recognized only in lower case.
+    ///
+    /// returns: &str
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0:BUNDLE").unwrap();
+    /// artifact.file_extension_with_case(false);
+    /// // => "jar"
+    /// artifact.file_extension_with_case(true);
+    /// // => "BUNDLE"
+    /// ```
+    pub fn file_extension_with_case(&self, case_sensitive: bool) -> &str {
+        packaging_extension(&self.packaging, case_sensitive)
+    }
+
+    /// Converts coordinates to the path string with default separator (`/`).
+    ///
+    /// returns: String
+    pub fn to_path(&self) -> String {
+        self.as_path_with_separator(DEFAULT_SEPARATOR)
+    }
+
+    /// Converts coordinates to the path, joining components with the platform separator.
+    ///
+    /// returns: PathBuf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.to_path_buf();
+    /// // => "io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar" on Unix
+    /// ```
+    pub fn to_path_buf(&self) -> PathBuf {
+        let mut path = PathBuf::new();
+
+        for segment in self.group_segments() {
+            path.push(segment);
+        }
+
+        path.push(&self.artifact_id);
+        path.push(self.full_version());
+        path.push(self.file_name());
+
+        path
+    }
+
+    /// Returns path of the artifact in the local repository, for use as a classpath entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_root`: Root directory of the local repository, like `~/.m2/repository`.
+    ///
+    /// returns: PathBuf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    /// use std::path::Path;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.classpath_entry(Path::new("/home/user/.m2/repository"));
+    /// // => "/home/user/.m2/repository/io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar" on Unix
+    /// ```
+    pub fn classpath_entry(&self, repo_root: &Path) -> PathBuf {
+        repo_root.join(self.to_path_buf())
+    }
+
+    /// Returns path of the artifact in the cache directory that mirrors remote repositories by
+    /// their host, like the Coursier cache does: `$base/$host/$groupPath/$artifactId/$version/$file`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base`: Root directory of the cache.
+    /// * `host`: Host of the remote repository, optionally followed by the repository path,
+    ///   like `repo1.maven.org/maven2`.
+    ///
+    /// returns: PathBuf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    /// use std::path::Path;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.coursier_cache_path(Path::new("/home/user/.cache/coursier/v1/https"), "repo1.maven.org/maven2");
+    /// // => "/home/user/.cache/coursier/v1/https/repo1.maven.org/maven2/io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar" on Unix
+    /// ```
+    pub fn coursier_cache_path(&self, base: &Path, host: &str) -> PathBuf {
+        let mut path = base.to_path_buf();
+
+        for segment in host.split(DEFAULT_SEPARATOR).filter(|s| !s.is_empty()) {
+            path.push(segment);
+        }
+
+        path.join(self.to_path_buf())
+    }
+
+    /// Checks whether path of the artifact stays within the root directory, so that components
+    /// like `..` or absolute paths in the coordinates cannot escape it.
+    ///
+    /// Path is normalized lexically, without accessing the file system, so symbolic links are
+    /// not followed. Path is rejected if:
+    ///
+    /// - Any of its segments is absolute (starts with a separator or a drive prefix).
+    /// - Any `..` segment climbs above the root, or path resolves to the root itself.
+    ///
+    /// Since group ID is split into segments at dots, it can never contain `..` segment, so a
+    /// malicious group ID (like `/etc`) is rejected by the first rule. Artifact ID and version
+    /// (like `../../..`) are rejected by the second rule once they climb out of the group ID
+    /// directories.
+    ///
+    /// # Arguments
+    ///
+    /// * `root`: Root directory the artifact is going to be written into.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    /// use std::path::Path;
+    ///
+    /// // Absolute segment in the group ID.
+    /// let artifact = Coordinates::new("/etc:artifact:1.0.0").unwrap();
+    /// assert!(!artifact.is_safe_relative_to(Path::new("/srv/repository")));
+    ///
+    /// // Climbs above the root through the group ID directories.
+    /// let artifact = Coordinates::new("io.github:../../..:1.0.0").unwrap();
+    /// assert!(!artifact.is_safe_relative_to(Path::new("/srv/repository")));
+    /// ```
+    pub fn is_safe_relative_to(&self, root: &Path) -> bool {
+        let mut path = root.to_path_buf();
+
+        for component in self.to_path_buf().components() {
+            match component {
+                Component::Normal(segment) => path.push(segment),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    if path == root || !path.pop() {
+                        return false;
+                    }
+                }
+                Component::RootDir | Component::Prefix(_) => return false,
+            }
+        }
+
+        path != root && path.starts_with(root)
+    }
+
+    /// Converts coordinates to the path string with custom separator.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator`: path separator.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// artifact.as_path_with_separator('\\');
+    /// // => "io\\github\\brawaru\\artifact\\1.0.0-SNAPSHOT\\artifact-1.0.0-SNAPSHOT.jar"
+    /// ```
+    pub fn as_path_with_separator(&self, separator: char) -> String {
+        let (mut path, file_name) = self.to_path_parts(separator);
+
+        path.push(separator);
+        path.push_str(file_name.as_str());
+
+        path
+    }
+
+    /// Converts coordinates to the path string with custom separator, replacing packaging.
+    ///
+    /// # Arguments
+    ///
+    /// * `packaging`: packaging to use instead of the one in these coordinates.
+    /// * `separator`: path separator.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.relative_path_for_packaging("pom", '/');
+    /// // => "io/github/brawaru/artifact/1.0.0/artifact-1.0.0.pom"
+    /// ```
+    pub fn relative_path_for_packaging(&self, packaging: &str, separator: char) -> String {
+        let mut coordinates = self.clone();
+        coordinates.packaging = packaging.to_string();
+
+        coordinates.as_path_with_separator(separator)
+    }
+
+    /// Returns iterator over the dot-separated segments of the group ID.
+    ///
+    /// returns: `impl Iterator<Item = &str>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.group_segments().collect::<Vec<_>>();
+    /// // => ["io", "github", "brawaru"]
+    /// ```
+    pub fn group_segments(&self) -> impl Iterator<Item = &str> {
+        self.group_id.split(VERSION_SEGMENT_SPLITTER)
+    }
+
+    /// Converts group ID to the path of the group directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator`: path separator.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.group_id_path('/');
+    /// // => "io/github/brawaru"
+    /// ```
+    pub fn group_id_path(&self, separator: char) -> String {
+        let mut path = String::new();
+
+        for (index, segment) in self.group_segments().enumerate() {
+            if index > 0 {
+                path.push(separator);
+            }
+
+            path.push_str(segment);
+        }
+
+        path
+    }
+
+    /// Converts coordinates to the path of version directory and the file name separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator`: path separator.
+    ///
+    /// returns: (String, String)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// artifact.to_path_parts('/');
+    /// // => ("io/github/brawaru/artifact/1.0.0-SNAPSHOT", "artifact-1.0.0-SNAPSHOT.jar")
+    /// ```
+    pub fn to_path_parts(&self, separator: char) -> (String, String) {
+        let mut path = self.group_id_path(separator);
+
+        path.push(separator);
+        path.push_str(&self.artifact_id);
+        path.push(separator);
+
+        path.push_str(self.full_version().as_str());
+
+        (path, self.file_name())
+    }
+
+    /// Resolves URL for the artifact using given base Maven server address.
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_server`: Address of remote Maven server
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// coords.resolve("https://brawaru.github.io/maven/");
+    /// // => "https://brawaru.github.io/maven/io/github/brawaru/artifact/1.0.0-SNAPSHOT/artifact-1.0.0-SNAPSHOT.jar"
+    /// ```
+    pub fn resolve(&self, maven_location: &str) -> String {
+        Coordinates::resolve_path(maven_location, &self.to_path())
+    }
+
+    /// Appends path to the base Maven server address, adding separator between them if needed.
+    ///
+    /// Each segment of the path is percent-encoded, leaving separators intact.
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_location`: Address of remote Maven server
+    /// * `path`: Path relative to the server address, with default separator (`/`).
+    ///
+    /// returns: String
+    fn resolve_path(maven_location: &str, path: &str) -> String {
+        let mut maven_location = maven_location.to_string();
+
+        if maven_location.chars().last().unwrap_or(' ') != '/' {
+            maven_location += "/";
+        }
+
+        let segments: Vec<String> = path.split(DEFAULT_SEPARATOR).map(percent_encode).collect();
+
+        maven_location += &segments.join(&DEFAULT_SEPARATOR.to_string());
+
+        maven_location
+    }
+
+    /// Returns path of the artifact-level `maven-metadata.xml` file with default separator
+    /// (`/`), which lists all the versions of the artifact.
+    ///
+    /// This is the `$groupId/$artifactId/maven-metadata.xml` layout, used to resolve the latest
+    /// or release version. Version of these coordinates is ignored.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap().metadata_path();
+    /// // => "io/github/brawaru/artifact/maven-metadata.xml"
+    /// ```
+    pub fn metadata_path(&self) -> String {
+        let mut path = self.group_id_path(DEFAULT_SEPARATOR);
+        path.push(DEFAULT_SEPARATOR);
+        path += &self.artifact_id;
+        path.push(DEFAULT_SEPARATOR);
+        path += MAVEN_METADATA_FILE;
+
+        path
+    }
+
+    /// Resolves URL of the artifact-level `maven-metadata.xml` file using given base Maven
+    /// server address, see [`metadata_path`][0] for the layout.
+    ///
+    /// [0]: Coordinates::metadata_path
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_location`: Address of remote Maven server
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// coords.metadata_url("https://brawaru.github.io/maven/");
+    /// // => "https://brawaru.github.io/maven/io/github/brawaru/artifact/maven-metadata.xml"
+    /// ```
+    pub fn metadata_url(&self, maven_location: &str) -> String {
+        Coordinates::resolve_path(maven_location, &self.metadata_path())
+    }
+
+    /// Resolves URL of the version-level `maven-metadata.xml` file using given base Maven server
+    /// address, which lists unique (timestamped) builds of the snapshot version.
+    ///
+    /// This is the `$groupId/$artifactId/$version/maven-metadata.xml` layout, only published for
+    /// snapshot versions.
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_location`: Address of remote Maven server
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// coords.snapshot_metadata_url("https://brawaru.github.io/maven/");
+    /// // => "https://brawaru.github.io/maven/io/github/brawaru/artifact/1.0.0-SNAPSHOT/maven-metadata.xml"
+    /// ```
+    pub fn snapshot_metadata_url(&self, maven_location: &str) -> String {
+        let (mut path, _) = self.to_path_parts(DEFAULT_SEPARATOR);
+        path.push(DEFAULT_SEPARATOR);
+        path += MAVEN_METADATA_FILE;
+
+        Coordinates::resolve_path(maven_location, &path)
+    }
+
+    /// Converts coordinates to the map of Maven field names to their values.
+    ///
+    /// Map contains `groupId`, `artifactId`, `version` (complete, including the label), `type`
+    /// (packaging) and `classifier`, if one is present.
+    ///
+    /// returns: `BTreeMap<&'static str, String>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// coords.to_field_map();
+    /// // => {"artifactId": "artifact", "groupId": "io.github.brawaru", "type": "jar", "version": "1.0.0-SNAPSHOT"}
+    /// ```
+    pub fn to_field_map(&self) -> BTreeMap<&'static str, String> {
+        let mut map = BTreeMap::new();
+
+        map.insert(FIELD_GROUP_ID, self.group_id.to_string());
+        map.insert(FIELD_ARTIFACT_ID, self.artifact_id.to_string());
+        map.insert(FIELD_VERSION, self.full_version());
+        map.insert(FIELD_TYPE, self.packaging.to_string());
+
+        if let Some(classifier) = &self.classifier {
+            map.insert(FIELD_CLASSIFIER, classifier.to_string());
+        }
+
+        map
+    }
+
+    /// Checks whether these coordinates refer to the snapshot version.
+    ///
+    /// Both `SNAPSHOT` label (in any case, per Maven convention) and unique (timestamped)
+    /// snapshot versions, like `1.0.0-20231201.101530-3`, are recognized.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let is_snapshot = |version: &str| {
+    ///     Coordinates::new(format!("io.github.brawaru:artifact:{}", version)).unwrap().is_snapshot()
+    /// };
+    ///
+    /// assert!(is_snapshot("1.0.0-SNAPSHOT"));
+    /// assert!(is_snapshot("1.0.0-20231001.123456-3"));
+    /// assert!(!is_snapshot("1.0.0"));
+    /// ```
+    pub fn is_snapshot(&self) -> bool {
+        self.has_snapshot_label() || self.snapshot_timestamp().is_some()
+    }
+
+    /// Checks whether version of these coordinates ends with the `SNAPSHOT` label.
+    ///
+    /// returns: bool
+    fn has_snapshot_label(&self) -> bool {
+        self.full_version()
+            .to_ascii_uppercase()
+            .ends_with(SNAPSHOT_LABEL)
+    }
+
+    /// Returns timestamp and build number of the unique (timestamped) snapshot version.
+    ///
+    /// returns: `Option<(String, u32)>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-20231201.101530-3").unwrap();
+    /// artifact.snapshot_timestamp();
+    /// // => Some(("20231201.101530", 3))
+    /// ```
+    pub fn snapshot_timestamp(&self) -> Option<(String, u32)> {
+        let full_version = self.full_version();
+        let (_, timestamp, build_number) = Coordinates::split_snapshot_timestamp(&full_version)?;
+
+        Some((timestamp.to_string(), build_number))
+    }
+
+    /// Splits unique snapshot version into the version stem (including trailing dash), timestamp
+    /// and build number.
+    ///
+    /// # Arguments
+    ///
+    /// * `version`: Complete version, like `1.0.0-20231201.101530-3`.
+    ///
+    /// returns: `Option<(&str, &str, u32)>`
+    fn split_snapshot_timestamp(version: &str) -> Option<(&str, &str, u32)> {
+        let mut parts = version.rsplitn(3, FILENAME_SPLITTER);
+
+        let (_, _, stem) = (parts.next()?, parts.next()?, parts.next()?);
+
+        if stem.is_empty() {
+            return None;
+        }
+
+        let stem = &version[..stem.len() + 1];
+        let (timestamp, build_number) =
+            Coordinates::parse_snapshot_timestamp(&version[stem.len()..])?;
+
+        Some((stem, timestamp, build_number))
+    }
+
+    /// Interpolates fields of these coordinates into the template string.
+    ///
+    /// Supported tokens are:
+    ///
+    /// - `{group}` — group ID.
+    /// - `{artifact}` — artifact ID.
+    /// - `{version}` — complete version, including the label.
+    /// - `{label}` — version label, empty if absent.
+    /// - `{packaging}` — packaging.
+    /// - `{classifier}` — classifier, empty if absent.
+    /// - `{file}` — file name, see [`file_name`][0].
+    /// - `{path}` — path with default separator, see [`to_path`][1].
+    ///
+    /// Unknown tokens are kept in the output as-is.
+    ///
+    /// [0]: Coordinates::file_name
+    /// [1]: Coordinates::to_path
+    ///
+    /// # Arguments
+    ///
+    /// * `template`: Template string with the tokens to replace.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// coords.format_template("{artifact}/{version}/{file}");
+    /// // => "artifact/1.0.0-SNAPSHOT/artifact-1.0.0-SNAPSHOT.jar"
+    /// ```
+    pub fn format_template(&self, template: &str) -> String {
+        let mut result = String::new();
+        let mut rest = template;
+
+        while let Some(start_index) = rest.find('{') {
+            result.push_str(&rest[..start_index]);
+            rest = &rest[start_index..];
+
+            let end_index = match rest.find('}') {
+                Some(end_index) => end_index,
+                None => break,
+            };
+
+            let token = &rest[..=end_index];
+
+            match &token[1..end_index] {
+                "group" => result.push_str(&self.group_id),
+                "artifact" => result.push_str(&self.artifact_id),
+                "version" => result.push_str(&self.full_version()),
+                "label" => result.push_str(self.version_label.as_deref().unwrap_or("")),
+                "packaging" => result.push_str(&self.packaging),
+                "classifier" => result.push_str(self.classifier.as_deref().unwrap_or("")),
+                "file" => result.push_str(&self.file_name()),
+                "path" => result.push_str(&self.to_path()),
+                _ => result.push_str(token),
+            }
+
+            rest = &rest[end_index + 1..];
+        }
+
+        result.push_str(rest);
+
+        result
+    }
+
+    /// Returns `$groupId:$artifactId:$version` part of the coordinates, regardless of packaging
+    /// and classifier.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:pom:sources").unwrap().to_gav();
+    /// // => "io.github.brawaru:artifact:1.0.0"
+    /// ```
+    pub fn to_gav(&self) -> String {
+        let mut gav = self.group_id.to_string();
+
+        gav += MAVEN_COORDINATES_SPLITTER;
+        gav += &self.artifact_id;
+
+        gav += MAVEN_COORDINATES_SPLITTER;
+        gav += &self.full_version();
+
+        gav
+    }
+
+    /// Converts coordinates to the contents of `pom.properties` file embedded in JARs.
+    ///
+    /// Output contains `groupId`, `artifactId` and `version` properties. Timestamp comment that
+    /// Maven writes is omitted to keep the output reproducible.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap().to_pom_properties();
+    /// // => "groupId=io.github.brawaru\nartifactId=artifact\nversion=1.0.0\n"
+    /// ```
+    pub fn to_pom_properties(&self) -> String {
+        let properties = [
+            ("groupId", self.group_id.to_string()),
+            ("artifactId", self.artifact_id.to_string()),
+            ("version", self.full_version()),
+        ];
+
+        let mut text = String::new();
+
+        for (key, value) in properties.iter() {
+            text += key;
+            text.push(PROPERTIES_SPLITTER);
+            text += value;
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Returns path to the `.lastUpdated` marker file Maven writes into the local repository when
+    /// it fails to download the artifact.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator`: path separator.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.last_updated_marker_path('/');
+    /// // => "io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar.lastUpdated"
+    /// ```
+    pub fn last_updated_marker_path(&self, separator: char) -> String {
+        let mut path = self.as_path_with_separator(separator);
+
+        path += EXTENSION_SPLITTER;
+        path += LAST_UPDATED_EXTENSION;
+
+        path
+    }
+
+    /// Resolves URL for the artifact in Artifactory storage REST API.
+    ///
+    /// # Arguments
+    ///
+    /// * `artifactory_location`: Address of Artifactory server
+    /// * `repository`: Key of the repository artifact is stored in
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// coords.resolve_artifactory_api("https://example.jfrog.io/artifactory", "libs-release");
+    /// // => "https://example.jfrog.io/artifactory/api/storage/libs-release/io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar"
+    /// ```
+    pub fn resolve_artifactory_api(&self, artifactory_location: &str, repository: &str) -> String {
+        let mut api_location = artifactory_location.to_string();
+
+        if !api_location.ends_with('/') {
+            api_location += "/";
+        }
+
+        api_location += ARTIFACTORY_STORAGE_API;
+        api_location += repository.trim_matches('/');
+
+        self.resolve(&api_location)
+    }
+
+    /// Resolves URL of the version directory of the artifact in the Nexus Repository UI.
+    ///
+    /// Directory path is percent-encoded, including the separators, as Nexus expects it.
+    ///
+    /// # Arguments
+    ///
+    /// * `nexus_location`: Address of Nexus server
+    /// * `repository`: Name of the repository artifact is stored in
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// coords.resolve_nexus_browse("https://nexus.example.com", "maven-releases");
+    /// // => "https://nexus.example.com/#browse/browse:maven-releases:io%2Fgithub%2Fbrawaru%2Fartifact%2F1.0.0"
+    /// ```
+    pub fn resolve_nexus_browse(&self, nexus_location: &str, repository: &str) -> String {
+        let mut browse_location = nexus_location.to_string();
+
+        if !browse_location.ends_with('/') {
+            browse_location += "/";
+        }
+
+        let (directory, _) = self.to_path_parts(DEFAULT_SEPARATOR);
+
+        browse_location += NEXUS_BROWSE_FRAGMENT;
+        browse_location += &percent_encode(repository);
+        browse_location += MAVEN_COORDINATES_SPLITTER;
+        browse_location += &percent_encode(&directory);
+
+        browse_location
+    }
+
+    /// Returns coordinates of the shaded artifact produced by Maven Shade Plugin alongside this
+    /// artifact.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap().shaded().file_name();
+    /// // => "artifact-1.0.0-shaded.jar"
+    /// ```
+    pub fn shaded(&self) -> Coordinates {
+        let mut shaded = self.clone();
+        shaded.classifier = Some(SHADED_CLASSIFIER.to_string());
+
+        shaded
+    }
+
+    /// Removes the directory prefix from the path of these coordinates.
+    ///
+    /// Prefix is only removed at directory boundaries, so `io/git` does not match
+    /// `io/github/...`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix`: directory prefix to remove, trailing separator is optional.
+    /// * `separator`: path separator.
+    ///
+    /// returns: `Option<String>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.strip_prefix_path("io/github", '/');
+    /// // => Some("brawaru/artifact/1.0.0/artifact-1.0.0.jar")
+    /// ```
+    pub fn strip_prefix_path(&self, prefix: &str, separator: char) -> Option<String> {
+        let path = self.as_path_with_separator(separator);
+        let prefix = prefix.trim_end_matches(separator);
+
+        if prefix.is_empty() {
+            return Some(path);
+        }
+
+        let rest = path.strip_prefix(prefix)?.strip_prefix(separator)?;
+
+        Some(rest.to_string())
+    }
+
+    /// Returns coordinates of the POM for this artifact.
+    ///
+    /// Since POMs cannot be classified, classifier is silently dropped. Use [`try_pom`][0] to
+    /// reject classified artifacts instead.
+    ///
+    /// [0]: Coordinates::try_pom
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:jar:sources").unwrap().pom().file_name();
+    /// // => "artifact-1.0.0.pom"
+    /// ```
+    pub fn pom(&self) -> Coordinates {
+        let mut pom = self.clone();
+        pom.packaging = POM_PACKAGING.to_string();
+        pom.classifier = None;
+
+        pom
+    }
+
+    /// Returns coordinates of the POM for this artifact, unless this artifact is classified.
+    ///
+    /// Unlike [`pom`][0], which drops the classifier, this fails for classified artifacts, since
+    /// their POM is shared with the main artifact and asking for it likely indicates a mistake.
+    ///
+    /// [0]: Coordinates::pom
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If these coordinates have no classifier (or it is empty), this will be
+    /// `Ok(Coordinates)`, otherwise `Err(CoordinatesError::ClassifiedPom)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0:jar:sources").unwrap();
+    /// assert!(artifact.try_pom().is_err());
+    /// ```
+    pub fn try_pom(&self) -> Result<Coordinates, CoordinatesError> {
+        match &self.classifier {
+            Some(classifier) if !classifier.is_empty() => Err(CoordinatesError::ClassifiedPom),
+            _ => Ok(self.pom()),
+        }
+    }
+
+    /// Returns coordinates of the sources JAR for this artifact.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:pom").unwrap().sources().file_name();
+    /// // => "artifact-1.0.0-sources.jar"
+    /// ```
+    pub fn sources(&self) -> Coordinates {
+        self.companion_jar(SOURCES_CLASSIFIER)
+    }
+
+    /// Returns coordinates of the Javadoc JAR for this artifact.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap().javadoc().file_name();
+    /// // => "artifact-1.0.0-javadoc.jar"
+    /// ```
+    pub fn javadoc(&self) -> Coordinates {
+        self.companion_jar(JAVADOC_CLASSIFIER)
+    }
+
+    /// Returns coordinates of the JAR with given classifier, published alongside this artifact.
+    ///
+    /// # Arguments
+    ///
+    /// * `classifier`: Classifier of the JAR.
+    ///
+    /// returns: Coordinates
+    fn companion_jar(&self, classifier: &str) -> Coordinates {
+        let mut companion = self.clone();
+        companion.packaging = MAVEN_STANDARD_PACKAGING.to_string();
+        companion.classifier = Some(classifier.to_string());
+
+        companion
+    }
+
+    /// Returns copy of these coordinates with the classifier moved into the packaging, as some
+    /// flat stores encode it, like `sources.jar` for `sources` classifier and `jar` packaging.
+    ///
+    /// Coordinates without classifier are returned unchanged.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:jar:sources").unwrap().flattened_classifier().file_name();
+    /// // => "artifact-1.0.0.sources.jar"
+    /// ```
+    pub fn flattened_classifier(&self) -> Coordinates {
+        let mut flattened = self.clone();
+
+        if let Some(classifier) = flattened.classifier.take() {
+            let mut packaging = classifier;
+            packaging += EXTENSION_SPLITTER;
+            packaging += self.file_extension();
+
+            flattened.packaging = packaging;
+        }
+
+        flattened
+    }
+
+    /// Returns coordinates of the Gradle module metadata file for this artifact.
+    ///
+    /// Like POM, module metadata is shared by all the artifacts of the module, so classifier is
+    /// dropped.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let module = Coordinates::new("io.github.brawaru:artifact:1.0.0:jar:sources").unwrap().gradle_module();
+    /// module.file_name();
+    /// // => "artifact-1.0.0.module"
+    /// module.checksum(ChecksumAlgorithm::Sha256).file_name();
+    /// // => "artifact-1.0.0.module.sha256"
+    /// ```
+    pub fn gradle_module(&self) -> Coordinates {
+        let mut module = self.clone();
+        module.packaging = GRADLE_MODULE_PACKAGING.to_string();
+        module.classifier = None;
+
+        module
+    }
+
+    /// Returns coordinates of the files Gradle publishes alongside the module metadata file of
+    /// this artifact: its checksums (MD5, SHA-1, SHA-256 and SHA-512) and the signature.
+    ///
+    /// returns: `Vec<Coordinates>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.gradle_module_siblings().last().unwrap().file_name();
+    /// // => "artifact-1.0.0.module.asc"
+    /// ```
+    pub fn gradle_module_siblings(&self) -> Vec<Coordinates> {
+        let module = self.gradle_module();
+
+        let mut siblings: Vec<Coordinates> = GRADLE_CHECKSUMS
+            .iter()
+            .map(|algorithm| module.checksum(*algorithm))
+            .collect();
+
+        siblings.push(module.signature());
+
+        siblings
+    }
+
+    /// Checks whether these coordinates refer to the primary artifact (like `jar`, `pom` or
+    /// `war`) rather than to the checksum or signature file published alongside it.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// assert!(artifact.is_primary_artifact());
+    /// assert!(!artifact.checksum(ChecksumAlgorithm::Sha1).is_primary_artifact());
+    /// ```
+    pub fn is_primary_artifact(&self) -> bool {
+        let extension = self
+            .packaging
+            .rsplit(EXTENSION_SPLITTER)
+            .next()
+            .unwrap_or_default();
+
+        !Coordinates::is_sidecar_extension(extension)
+    }
+
+    /// Returns coordinates of the checksum file for this artifact.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm`: Algorithm of the checksum.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.checksum(ChecksumAlgorithm::Sha1).file_name();
+    /// // => "artifact-1.0.0.jar.sha1"
+    /// ```
+    pub fn checksum(&self, algorithm: ChecksumAlgorithm) -> Coordinates {
+        self.with_file_suffix(algorithm.extension())
+    }
+
+    /// Returns file name of the checksum file for this artifact.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm`: Algorithm of the checksum.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.checksum_file_name(ChecksumAlgorithm::Sha256);
+    /// // => "artifact-1.0.0.jar.sha256"
+    /// ```
+    pub fn checksum_file_name(&self, algorithm: ChecksumAlgorithm) -> String {
+        self.checksum(algorithm).file_name()
+    }
+
+    /// Resolves URL of the checksum file for this artifact using given base Maven server
+    /// address.
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_location`: Address of remote Maven server
+    /// * `algorithm`: Algorithm of the checksum.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.checksum_url("https://brawaru.github.io/maven/", ChecksumAlgorithm::Md5);
+    /// // => "https://brawaru.github.io/maven/io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar.md5"
+    /// ```
+    pub fn checksum_url(&self, maven_location: &str, algorithm: ChecksumAlgorithm) -> String {
+        self.checksum(algorithm).resolve(maven_location)
+    }
+
+    /// Returns coordinates of the detached PGP signature file for this artifact.
+    ///
+    /// Signatures of the POM or checksum files can be obtained by composing, like
+    /// `coordinates.pom().signature()`.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.pom().signature().file_name();
+    /// // => "artifact-1.0.0.pom.asc"
+    /// ```
+    pub fn signature(&self) -> Coordinates {
+        self.with_file_suffix(SIGNATURE_EXTENSION)
+    }
+
+    /// Returns file name of the detached PGP signature file for this artifact.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.signature_file_name();
+    /// // => "artifact-1.0.0.jar.asc"
+    /// ```
+    pub fn signature_file_name(&self) -> String {
+        self.signature().file_name()
+    }
+
+    /// Resolves URL of the detached PGP signature file for this artifact using given base Maven
+    /// server address.
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_location`: Address of remote Maven server
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.signature_url("https://brawaru.github.io/maven/");
+    /// // => "https://brawaru.github.io/maven/io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar.asc"
+    /// ```
+    pub fn signature_url(&self, maven_location: &str) -> String {
+        self.signature().resolve(maven_location)
+    }
+
+    /// Resolves URLs of the checksum files for the standard artifact set: main artifact (without
+    /// classifier), its POM, sources and Javadoc JARs.
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_location`: Address of remote Maven server
+    /// * `algorithms`: Algorithms of the checksums to include.
+    ///
+    /// returns: `Vec<String>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.all_checksum_urls("https://brawaru.github.io/maven/", &[ChecksumAlgorithm::Sha1]).len();
+    /// // => 4
+    /// ```
+    pub fn all_checksum_urls(
+        &self,
+        maven_location: &str,
+        algorithms: &[ChecksumAlgorithm],
+    ) -> Vec<String> {
+        let mut main = self.clone();
+        main.classifier = None;
+
+        let artifacts = [self.pom(), self.sources(), self.javadoc()];
+
+        std::iter::once(main)
+            .chain(artifacts)
+            .flat_map(|artifact| {
+                algorithms
+                    .iter()
+                    .map(move |algorithm| artifact.checksum_url(maven_location, *algorithm))
+            })
+            .collect()
+    }
+
+    /// Returns copy of these coordinates with the extension appended to the file extension, so
+    /// that it's appended to the complete file name.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix`: Extension to append, leading dot is optional.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0:pom").unwrap();
+    /// artifact.with_file_suffix(".lastModified").file_name();
+    /// // => "artifact-1.0.0.pom.lastModified"
+    /// ```
+    pub fn with_file_suffix(&self, suffix: &str) -> Coordinates {
+        let mut coordinates = self.clone();
+        coordinates.packaging = self.file_extension().to_string();
+        coordinates.packaging += EXTENSION_SPLITTER;
+        coordinates.packaging += suffix.strip_prefix(EXTENSION_SPLITTER).unwrap_or(suffix);
+
+        coordinates
+    }
+
+    /// Returns these coordinates with the classifier replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `classifier`: New classifier.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.with_classifier("sources").to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0:jar:sources"
+    /// ```
+    pub fn with_classifier<S: Into<String>>(mut self, classifier: S) -> Self {
+        self.classifier = Some(classifier.into());
+        self
+    }
+
+    /// Returns these coordinates with the packaging replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `packaging`: New packaging.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.with_packaging("pom").to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0:pom"
+    /// ```
+    pub fn with_packaging<S: Into<String>>(mut self, packaging: S) -> Self {
+        self.packaging = packaging.into();
+        self
+    }
+
+    /// Returns these coordinates with the version replaced. Version is split into version, label
+    /// and build metadata the same way as [`Coordinates::new`] does it.
+    ///
+    /// # Arguments
+    ///
+    /// * `version`: New complete version.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// let artifact = artifact.with_version("1.1.0-SNAPSHOT");
+    ///
+    /// assert_eq!(artifact.version, "1.1.0");
+    /// assert_eq!(artifact.version_label.as_deref(), Some("SNAPSHOT"));
+    /// ```
+    pub fn with_version<S: Into<String>>(mut self, version: S) -> Self {
+        let full_version = version.into();
+        let (version, build_metadata) = Coordinates::split_build_metadata(&full_version);
+        let (version, version_label) = Coordinates::split_version(version);
+
+        self.version = version.to_string();
+        self.version_label = version_label.map(|label| label.to_string());
+        self.build_metadata = build_metadata.map(|metadata| metadata.to_string());
+        self
+    }
+
+    /// Returns these coordinates with the version label replaced, or removed if `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label`: New version label.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// artifact.with_version_label(None).to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0"
+    /// ```
+    pub fn with_version_label(mut self, label: Option<String>) -> Self {
+        self.version_label = label;
+        self
+    }
+
+    /// Returns coordinates of the checksum file for the POM of this artifact.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm`: Algorithm of the checksum.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0:jar:sources").unwrap();
+    /// artifact.pom_checksum(ChecksumAlgorithm::Sha1).file_name();
+    /// // => "artifact-1.0.0.pom.sha1"
+    /// ```
+    pub fn pom_checksum(&self, algorithm: ChecksumAlgorithm) -> Coordinates {
+        self.pom().checksum(algorithm)
+    }
+
+    /// Returns checksum coordinates for the artifact and its POM, keyed by the checksum file
+    /// name.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithms`: Algorithms of the checksums to include.
+    ///
+    /// returns: `BTreeMap<String, Coordinates>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ChecksumAlgorithm, Coordinates};
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.verification_map(&[ChecksumAlgorithm::Sha1]);
+    /// // => {"artifact-1.0.0.jar.sha1": io.github.brawaru:artifact:1.0.0:jar.sha1,
+    /// //     "artifact-1.0.0.pom.sha1": io.github.brawaru:artifact:1.0.0:pom.sha1}
+    /// ```
+    pub fn verification_map(
+        &self,
+        algorithms: &[ChecksumAlgorithm],
+    ) -> BTreeMap<String, Coordinates> {
+        let mut map = BTreeMap::new();
+
+        for artifact in [self.clone(), self.pom()].iter() {
+            for algorithm in algorithms {
+                let checksum = artifact.checksum(*algorithm);
+
+                map.insert(checksum.file_name(), checksum);
+            }
+        }
+
+        map
+    }
+
+    /// Returns file names of the artifact, its POM and their checksums, as they would be listed
+    /// in the version directory index.
+    ///
+    /// returns: `Vec<String>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap().index_links();
+    /// // => ["artifact-1.0.0.jar", "artifact-1.0.0.jar.md5", "artifact-1.0.0.jar.sha1",
+    /// //     "artifact-1.0.0.pom", "artifact-1.0.0.pom.md5", "artifact-1.0.0.pom.sha1"]
+    /// ```
+    pub fn index_links(&self) -> Vec<String> {
+        let mut artifacts = vec![self.clone()];
+        let pom = self.pom();
+
+        if pom != *self {
+            artifacts.push(pom);
+        }
+
+        let mut links = Vec::new();
+
+        for artifact in artifacts {
+            links.push(artifact.file_name());
+
+            for algorithm in STANDARD_CHECKSUMS.iter() {
+                links.push(artifact.checksum(*algorithm).file_name());
+            }
+        }
+
+        links
+    }
+
+    /// Checks whether the file name matches the file name of this artifact.
+    ///
+    /// For snapshot versions unique (timestamped) file names are accepted as well, like
+    /// `artifact-1.0.0-20231001.123456-3.jar` for `1.0.0-SNAPSHOT` version.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name`: File name to check.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// artifact.matches_file_name("artifact-1.0.0-20231001.123456-3.jar");
+    /// // => true
+    /// ```
+    pub fn matches_file_name(&self, file_name: &str) -> bool {
+        if file_name == self.file_name() {
+            return true;
+        }
+
+        if !self.has_snapshot_label() {
+            return false;
+        }
+
+        let full_version = self.full_version();
+        let version_stem = &full_version[..full_version.len() - SNAPSHOT_LABEL.len()];
+
+        let mut prefix = self.artifact_id.to_string();
+        prefix += FILENAME_SPLITTER;
+        prefix += version_stem;
+
+        let mut suffix = String::new();
+
+        if let Some(classifier) = &self.classifier {
+            suffix += FILENAME_SPLITTER;
+            suffix += classifier;
+        }
+
+        suffix += EXTENSION_SPLITTER;
+        suffix += self.file_extension();
+
+        file_name
+            .strip_prefix(prefix.as_str())
+            .and_then(|rest| rest.strip_suffix(suffix.as_str()))
+            .and_then(Coordinates::parse_snapshot_timestamp)
+            .is_some()
+    }
+
+    /// Returns unique (timestamped) snapshot version for the next build deployed.
+    ///
+    /// The `SNAPSHOT` label (or timestamp and build number of unique snapshot version) is replaced
+    /// with `$timestamp-$buildNumber`, where build number is the one after the current. For
+    /// versions that are not snapshots the suffix is appended to the complete version instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_build`: Number of the last deployed build, `0` if none were deployed.
+    /// * `timestamp`: Deployment timestamp in `yyyyMMdd.HHmmss` format (UTC).
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0-SNAPSHOT").unwrap();
+    /// artifact.next_snapshot_build(3, "20231201.101530");
+    /// // => "1.0.0-20231201.101530-4"
+    /// ```
+    pub fn next_snapshot_build(&self, current_build: u32, timestamp: &str) -> String {
+        let mut version = self.full_version();
+
+        if self.has_snapshot_label() {
+            version.truncate(version.len() - SNAPSHOT_LABEL.len());
+        } else if let Some((stem, _, _)) = Coordinates::split_snapshot_timestamp(&version) {
+            version = stem.to_string();
+        } else {
+            version += FILENAME_SPLITTER;
+        }
+
+        version += timestamp;
+        version += FILENAME_SPLITTER;
+        version += &(current_build + 1).to_string();
+
+        version
+    }
+
+    /// Parses unique snapshot version suffix in the `$timestamp-$buildNumber` format, where
+    /// timestamp follows `yyyyMMdd.HHmmss` format.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix`: Unique snapshot version suffix, like `20231001.123456-3`.
+    ///
+    /// returns: `Option<(&str, u32)>`
+    fn parse_snapshot_timestamp(suffix: &str) -> Option<(&str, u32)> {
+        let split_index = suffix.rfind(FILENAME_SPLITTER)?;
+        let (timestamp, build_number) = (&suffix[..split_index], &suffix[split_index + 1..]);
+
+        let is_digits =
+            |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+
+        let mut timestamp_parts = timestamp.split(VERSION_SEGMENT_SPLITTER);
+
+        match (
+            timestamp_parts.next(),
+            timestamp_parts.next(),
+            timestamp_parts.next(),
+        ) {
+            (Some(date), Some(time), None) if is_digits(date, 8) && is_digits(time, 6) => {}
+            _ => return None,
+        }
+
+        if !build_number.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        Some((timestamp, build_number.parse().ok()?))
+    }
+
+    /// Extracts classifiers of this artifact from the listing of its version directory.
+    ///
+    /// Only file names with the same artifact ID, version and packaging are considered, `None`
+    /// stands for the main artifact without classifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_names`: File names in the version directory.
+    ///
+    /// returns: `Vec<Option<String>>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let artifact = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// artifact.classifiers_from_listing(&["artifact-1.0.0.jar", "artifact-1.0.0-sources.jar"]);
+    /// // => [None, Some("sources")]
+    /// ```
+    pub fn classifiers_from_listing(&self, file_names: &[&str]) -> Vec<Option<String>> {
+        let mut stem = self.artifact_id.to_string();
+        stem += FILENAME_SPLITTER;
+        stem += &self.full_version();
+
+        let mut extension = EXTENSION_SPLITTER.to_string();
+        extension += self.file_extension();
+
+        file_names
+            .iter()
+            .filter_map(|file_name| {
+                let rest = file_name
+                    .strip_prefix(stem.as_str())?
+                    .strip_suffix(extension.as_str())?;
+
+                if rest.is_empty() {
+                    return Some(None);
+                }
+
+                let classifier = rest.strip_prefix(FILENAME_SPLITTER)?;
+
+                Some(Some(classifier.to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns the longest directory prefix shared by paths of these and other coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: Coordinates to compare path with.
+    /// * `separator`: path separator.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let first = Coordinates::new("io.github.brawaru:first:1.0.0").unwrap();
+    /// let second = Coordinates::new("io.github.brawaru:second:1.0.0").unwrap();
+    /// first.common_path_prefix(&second, '/');
+    /// // => "io/github/brawaru"
+    /// ```
+    pub fn common_path_prefix(&self, other: &Coordinates, separator: char) -> String {
+        let (path, _) = self.to_path_parts(separator);
+        let (other_path, _) = other.to_path_parts(separator);
+
+        let common: Vec<&str> = path
+            .split(separator)
+            .zip(other_path.split(separator))
+            .take_while(|(directory, other_directory)| directory == other_directory)
+            .map(|(directory, _)| directory)
+            .collect();
+
+        common.join(&separator.to_string())
+    }
+
+    /// Returns copy of these coordinates with group ID and artifact ID lower-cased, as Maven
+    /// recommends. Version, packaging and classifier are left untouched.
+    ///
+    /// Unlike [`canonicalize`][0], nothing else is changed.
+    ///
+    /// Note that normalized coordinates may resolve to a different path in repositories that are
+    /// case-sensitive (like most of the ones served from Linux file systems), so this should only
+    /// be applied when mixed-case IDs are known to be a mistake.
+    ///
+    /// [0]: Coordinates::canonicalize
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("IO.GitHub.Brawaru:Artifact:1.0.0-RC1:jar:Linux").unwrap().normalized().to_string();
+    /// // => "io.github.brawaru:artifact:1.0.0-RC1:jar:Linux"
+    /// ```
+    pub fn normalized(&self) -> Coordinates {
+        let mut coordinates = self.clone();
+        coordinates.group_id = self.group_id.to_lowercase();
+        coordinates.artifact_id = self.artifact_id.to_lowercase();
+
+        coordinates
+    }
+
+    /// Returns canonical form of these coordinates, so that coordinates written differently but
+    /// referring to the same artifact become equal.
+    ///
+    /// Normalization steps are:
+    ///
+    /// 1. Whitespace around every component is trimmed.
+    /// 2. Group ID and artifact ID are lower-cased.
+    /// 3. Empty version label, build metadata and classifier are replaced with `None`.
+    /// 4. Empty packaging and any spelling of `jar` are replaced with the standard packaging.
+    ///
+    /// Version and classifier keep their case, since they are case-sensitive in repositories.
+    ///
+    /// returns: Coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new(" IO.GitHub.Brawaru : Artifact : 1.0.0 : JAR : ").unwrap().to_canonical_string();
+    /// // => "io.github.brawaru:artifact:1.0.0"
+    /// ```
+    pub fn canonicalize(&self) -> Coordinates {
+        let normalize_optional = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        let packaging = Coordinates::normalize_packaging(&self.packaging);
+
+        Coordinates {
+            group_id: self.group_id.trim().to_lowercase(),
+            artifact_id: self.artifact_id.trim().to_lowercase(),
+            version: self.version.trim().to_string(),
+            version_label: normalize_optional(&self.version_label),
+            build_metadata: normalize_optional(&self.build_metadata),
+            packaging: packaging.to_string(),
+            classifier: normalize_optional(&self.classifier),
+            explicit_packaging: false,
+        }
+    }
+
+    /// Returns string of the canonical form of these coordinates, see
+    /// [`canonicalize`][0].
+    ///
+    /// [0]: Coordinates::canonicalize
+    ///
+    /// returns: String
+    pub fn to_canonical_string(&self) -> String {
+        self.canonicalize().to_string()
+    }
+
+    /// Converts coordinates to the Coursier dependency notation.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:zip:dist").unwrap().to_coursier();
+    /// // => "io.github.brawaru:artifact:1.0.0,classifier=dist,type=zip"
+    /// ```
+    pub fn to_coursier(&self) -> String {
+        let mut notation = self.to_gav();
+
+        if let Some(classifier) = &self.classifier {
+            notation += COURSIER_ATTRIBUTES_SPLITTER;
+            notation += "classifier=";
+            notation += classifier;
+        }
+
+        if self.packaging != MAVEN_STANDARD_PACKAGING {
+            notation += COURSIER_ATTRIBUTES_SPLITTER;
+            notation += "type=";
+            notation += &self.packaging;
+        }
+
+        notation
+    }
+
+    /// Converts coordinates to the Eclipse Aether artifact string, the inverse of
+    /// [`from_aether`][0].
+    ///
+    /// Extension is omitted for `jar` packaging, unless classifier is present, since Aether
+    /// requires the extension to precede the classifier.
+    ///
+    /// [0]: Coordinates::from_aether
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:zip:dist").unwrap().to_aether();
+    /// // => "io.github.brawaru:artifact:zip:dist:1.0.0"
+    /// ```
+    pub fn to_aether(&self) -> String {
+        let mut artifact = self.group_id.to_string();
+
+        artifact += MAVEN_COORDINATES_SPLITTER;
+        artifact += &self.artifact_id;
+
+        if self.packaging != MAVEN_STANDARD_PACKAGING || self.classifier.is_some() {
+            artifact += MAVEN_COORDINATES_SPLITTER;
+            artifact += &self.packaging;
+        }
+
+        if let Some(classifier) = &self.classifier {
+            artifact += MAVEN_COORDINATES_SPLITTER;
+            artifact += classifier;
+        }
+
+        artifact += MAVEN_COORDINATES_SPLITTER;
+        artifact += &self.full_version();
+
+        artifact
+    }
+
+    /// Converts coordinates to the Gradle dependency notation, the inverse of
+    /// [`from_gradle`][0].
+    ///
+    /// Packaging other than `jar` is written as `@$extension` after the classifier.
+    ///
+    /// [0]: Coordinates::from_gradle
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:zip:dist").unwrap().to_gradle();
+    /// // => "io.github.brawaru:artifact:1.0.0:dist@zip"
+    /// ```
+    pub fn to_gradle(&self) -> String {
+        let mut notation = self.to_gav();
+
+        if let Some(classifier) = &self.classifier {
+            notation += MAVEN_COORDINATES_SPLITTER;
+            notation += classifier;
+        }
+
+        if self.packaging != MAVEN_STANDARD_PACKAGING {
+            notation.push(GRADLE_EXTENSION_SPLITTER);
+            notation += &self.packaging;
+        }
+
+        notation
+    }
+
+    /// Converts coordinates to the slug that is safe to use as a file name.
+    ///
+    /// Slug consists of group ID, artifact ID, complete version, packaging and classifier (if
+    /// any) joined with double underscores (`__`). Within the components, every character other
+    /// than ASCII letters, digits, `-`, `.` and `~` is percent-encoded, including `:`, path
+    /// separators and underscores, so that distinct coordinates never produce the same slug.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0+build_5:jar:sources").unwrap().to_slug();
+    /// // => "io.github.brawaru__artifact__1.0.0%2Bbuild%5F5__jar__sources"
+    /// ```
+    pub fn to_slug(&self) -> String {
+        let full_version = self.full_version();
+
+        let components = [
+            Some(self.group_id.as_str()),
+            Some(self.artifact_id.as_str()),
+            Some(full_version.as_str()),
+            Some(self.packaging.as_str()),
+            self.classifier.as_deref(),
+        ];
+
+        let components: Vec<String> = components
+            .iter()
+            .flatten()
+            .map(|component| percent_encode(component).replace('_', "%5F"))
+            .collect();
+
+        components.join(SLUG_SEPARATOR)
+    }
+
+    /// Checks whether these and other coordinates share the artifact directory, which holds
+    /// directories of all the versions and artifact-level `maven-metadata.xml`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: Coordinates to compare with.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let first = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// let second = Coordinates::new("io.github.brawaru:artifact:2.0.0:pom").unwrap();
+    /// first.same_artifact_dir(&second);
+    /// // => true
+    /// ```
+    pub fn same_artifact_dir(&self, other: &Coordinates) -> bool {
+        self.group_id == other.group_id && self.artifact_id == other.artifact_id
+    }
+
+    /// Converts coordinates to the artifact string used by `maven_install` in Bazel's
+    /// `rules_jvm_external`.
+    ///
+    /// It follows the format `$groupId:$artifactId[:$packaging[:$classifier]]:$version`, where
+    /// packaging is only included if it is not standard or classifier is present.
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0:jar:sources").unwrap().to_bazel();
+    /// // => "io.github.brawaru:artifact:jar:sources:1.0.0"
+    /// ```
+    pub fn to_bazel(&self) -> String {
+        let mut artifact = self.group_id.to_string();
+
+        artifact += MAVEN_COORDINATES_SPLITTER;
+        artifact += &self.artifact_id;
+
+        if self.packaging != MAVEN_STANDARD_PACKAGING || self.classifier.is_some() {
+            artifact += MAVEN_COORDINATES_SPLITTER;
+            artifact += &self.packaging;
+
+            if let Some(classifier) = &self.classifier {
+                artifact += MAVEN_COORDINATES_SPLITTER;
+                artifact += classifier;
+            }
+        }
+
+        artifact += MAVEN_COORDINATES_SPLITTER;
+        artifact += &self.full_version();
+
+        artifact
+    }
+
+    /// Checks whether these and other coordinates are equal, treating empty packaging and any
+    /// spelling of `jar` (like `JAR` or ` Jar `) the same as the standard packaging, like
+    /// [`canonicalize`][0] does.
+    ///
+    /// All the other components, including non-standard packaging, must be equal. Note that `==`
+    /// already ignores whether standard packaging was written explicitly.
+    ///
+    /// [0]: Coordinates::canonicalize
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: Coordinates to compare with.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let implicit = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// let explicit = Coordinates::new("io.github.brawaru:artifact:1.0.0:jar").unwrap();
+    /// implicit.eq_normalized(&explicit);
+    /// // => true
+    /// ```
+    pub fn eq_normalized(&self, other: &Coordinates) -> bool {
+        self.group_id == other.group_id
+            && self.artifact_id == other.artifact_id
+            && self.version == other.version
+            && self.version_label == other.version_label
+            && self.build_metadata == other.build_metadata
+            && self.classifier == other.classifier
+            && Coordinates::normalize_packaging(&self.packaging)
+                == Coordinates::normalize_packaging(&other.packaging)
+    }
+
+    /// Normalizes packaging, replacing empty packaging and any spelling of `jar` with the
+    /// standard packaging.
+    ///
+    /// # Arguments
+    ///
+    /// * `packaging`: Packaging to normalize.
+    ///
+    /// returns: &str
+    fn normalize_packaging(packaging: &str) -> &str {
+        let packaging = packaging.trim();
+
+        if packaging.is_empty() || packaging.eq_ignore_ascii_case(MAVEN_STANDARD_PACKAGING) {
+            MAVEN_STANDARD_PACKAGING
+        } else {
+            packaging
+        }
+    }
+
+    /// Checks whether other coordinates refer to the sibling artifact, built from the same POM
+    /// and only differing by the classifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: Coordinates to compare with.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// let main = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// let sources = Coordinates::new("io.github.brawaru:artifact:1.0.0:jar:sources").unwrap();
+    /// main.is_sibling_of(&sources);
+    /// // => true
+    /// ```
+    pub fn is_sibling_of(&self, other: &Coordinates) -> bool {
+        self.same_artifact_dir(other)
+            && self.full_version() == other.full_version()
+            && self.packaging == other.packaging
+            && self.classifier != other.classifier
+    }
+
+    /// Returns group ID as a domain name in forward order, if the group ID looks like a valid
+    /// reverse domain name.
+    ///
+    /// Group ID is considered a valid reverse domain name if it has at least two segments, each
+    /// of them is a valid DNS label (up to 63 letters, digits and hyphens, not starting or ending
+    /// with a hyphen), and the first segment (top-level domain) consists only of letters.
+    ///
+    /// returns: `Option<String>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Coordinates;
+    ///
+    /// Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap().group_as_domain();
+    /// // => Some("brawaru.github.io")
+    /// ```
+    pub fn group_as_domain(&self) -> Option<String> {
+        let segments: Vec<&str> = self.group_segments().collect();
+
+        let is_label = |label: &str| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        };
+
+        let is_tld = segments[0].bytes().all(|b| b.is_ascii_alphabetic());
+
+        if segments.len() < 2 || !is_tld || !segments.iter().all(|s| is_label(s)) {
+            return None;
+        }
+
+        let domain: Vec<&str> = segments.into_iter().rev().collect();
+
+        Some(domain.join(VERSION_SEGMENT_SPLITTER))
+    }
+}
+
+/// Parses all coordinates listed in the Gradle dependencies lockfile.
+///
+/// Each entry in the lockfile looks like `$groupId:$artifactId:$version=$configurations`, the
+/// configurations suffix is stripped. Comments, blank lines and service entries (like
+/// `empty=annotationProcessor`) are ignored.
+///
+/// # Arguments
+///
+/// * `text`: Contents of the `gradle.lockfile`.
+///
+/// returns: `Vec<Coordinates>`
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::parse_gradle_lockfile;
+///
+/// parse_gradle_lockfile("com.example:lib:1.0.0=compileClasspath,runtimeClasspath");
+/// // => [com.example:lib:1.0.0]
+/// ```
+pub fn parse_gradle_lockfile(text: &str) -> Vec<Coordinates> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with(GRADLE_LOCKFILE_COMMENT))
+        .filter_map(|line| {
+            let coordinates = match line.find(GRADLE_LOCKFILE_SPLITTER) {
+                Some(split_index) => &line[..split_index],
+                None => line,
+            };
+
+            Coordinates::new(coordinates).ok()
+        })
+        .collect()
+}
+
+/// Finds all coordinates referring to the snapshot versions.
+///
+/// # Arguments
+///
+/// * `coords`: Coordinates to look through.
+///
+/// returns: `Vec<&Coordinates>`
+pub fn find_snapshots(coords: &[Coordinates]) -> Vec<&Coordinates> {
+    coords.iter().filter(|c| c.is_snapshot()).collect()
+}
+
+/// Algorithm used to compute checksum of the artifact published alongside it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    /// MD5, published with `.md5` extension.
+    Md5,
+
+    /// SHA-1, published with `.sha1` extension.
+    Sha1,
+
+    /// SHA-256, published with `.sha256` extension.
+    Sha256,
+
+    /// SHA-512, published with `.sha512` extension.
+    Sha512,
+}
+
+impl ChecksumAlgorithm {
+    /// Returns extension appended to the artifact file name to get the checksum file name.
+    ///
+    /// returns: &'static str
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "md5",
+            ChecksumAlgorithm::Sha1 => "sha1",
+            ChecksumAlgorithm::Sha256 => "sha256",
+            ChecksumAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    /// Computes hex-encoded digest of the data.
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: Data to compute digest for.
+    ///
+    /// returns: String
+    #[cfg(feature = "checksum")]
+    fn hex_digest(&self, data: &[u8]) -> String {
+        use sha2::Digest;
+
+        let digest = match self {
+            ChecksumAlgorithm::Md5 => md5::Md5::digest(data).to_vec(),
+            ChecksumAlgorithm::Sha1 => sha1::Sha1::digest(data).to_vec(),
+            ChecksumAlgorithm::Sha256 => sha2::Sha256::digest(data).to_vec(),
+            ChecksumAlgorithm::Sha512 => sha2::Sha512::digest(data).to_vec(),
+        };
+
+        digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
+
+/// Checksum of the artifact, as published in the checksum file alongside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtifactChecksum {
+    /// Coordinates of the artifact this checksum is for.
+    pub coordinates: Coordinates,
+
+    /// Algorithm used to compute the checksum.
+    pub algorithm: ChecksumAlgorithm,
+
+    /// Hex-encoded digest of the artifact.
+    pub digest: String,
+}
+
+impl ArtifactChecksum {
+    /// Creates new artifact checksum from the contents of the checksum file.
+    ///
+    /// Checksum files may contain the file name after the digest, only the first word is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Coordinates of the artifact.
+    /// * `algorithm`: Algorithm used to compute the checksum.
+    /// * `contents`: Contents of the checksum file.
+    ///
+    /// returns: ArtifactChecksum
+    pub fn new(coordinates: Coordinates, algorithm: ChecksumAlgorithm, contents: &str) -> Self {
+        let digest = contents.split_whitespace().next().unwrap_or("");
+
+        Self {
+            coordinates,
+            algorithm,
+            digest: digest.to_ascii_lowercase(),
+        }
+    }
+
+    /// Verifies that the data matches this checksum.
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: Contents of the artifact file.
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{ArtifactChecksum, ChecksumAlgorithm, Coordinates};
+    ///
+    /// let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap();
+    /// let checksum = ArtifactChecksum::new(
+    ///     coords,
+    ///     ChecksumAlgorithm::Sha1,
+    ///     "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d",
+    /// );
+    ///
+    /// assert!(checksum.verify(b"hello"));
+    /// ```
+    #[cfg(feature = "checksum")]
+    pub fn verify(&self, data: &[u8]) -> bool {
+        self.algorithm.hex_digest(data) == self.digest
+    }
+}
+
+/// Coordinates wrapper that memoizes the computed path and string representation.
+///
+/// Cached values are invalidated whenever wrapped coordinates are borrowed mutably.
+#[derive(Debug, Clone)]
+pub struct CachedCoordinates {
+    coordinates: Coordinates,
+    path: OnceCell<String>,
+    string: OnceCell<String>,
+}
+
+impl CachedCoordinates {
+    /// Wraps coordinates to cache their computed values.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Coordinates to wrap.
+    ///
+    /// returns: CachedCoordinates
+    pub fn new(coordinates: Coordinates) -> Self {
+        Self {
+            coordinates,
+            path: OnceCell::new(),
+            string: OnceCell::new(),
+        }
+    }
+
+    /// Returns wrapped coordinates.
+    ///
+    /// returns: &Coordinates
+    pub fn coordinates(&self) -> &Coordinates {
+        &self.coordinates
+    }
+
+    /// Returns wrapped coordinates for modification, invalidating cached values.
+    ///
+    /// returns: &mut Coordinates
+    pub fn coordinates_mut(&mut self) -> &mut Coordinates {
+        self.path.take();
+        self.string.take();
+
+        &mut self.coordinates
+    }
+
+    /// Unwraps the coordinates, dropping cached values.
+    ///
+    /// returns: Coordinates
+    pub fn into_inner(self) -> Coordinates {
+        self.coordinates
+    }
+
+    /// Returns cached path string with default separator (`/`), see [`Coordinates::to_path`].
+    ///
+    /// returns: &str
+    pub fn to_path(&self) -> &str {
+        self.path.get_or_init(|| self.coordinates.to_path())
+    }
+
+    /// Returns cached coordinates string, see [`Coordinates::to_string`][ToString::to_string].
+    ///
+    /// returns: &str
+    pub fn as_str(&self) -> &str {
+        self.string.get_or_init(|| self.coordinates.to_string())
+    }
+}
+
+impl From<Coordinates> for CachedCoordinates {
+    fn from(coordinates: Coordinates) -> Self {
+        CachedCoordinates::new(coordinates)
+    }
+}
+
+/// Cache of the parse results, for inputs that are parsed repeatedly.
+///
+/// Results (including errors) are memoized by the input string, so each distinct string is
+/// parsed only once.
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::ParseCache;
+///
+/// let mut cache = ParseCache::new();
+///
+/// let first = cache.parse("io.github.brawaru:artifact:1.0.0").unwrap();
+/// let second = cache.parse("io.github.brawaru:artifact:1.0.0").unwrap();
+///
+/// assert_eq!(first, second);
+/// assert_eq!(cache.misses(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseCache {
+    results: HashMap<String, Result<Coordinates, CoordinatesError>>,
+    misses: usize,
+}
+
+impl ParseCache {
+    /// Creates new empty cache.
+    ///
+    /// returns: ParseCache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses coordinates string as [`Coordinates::new`] does, reusing the result if the same
+    /// string was parsed before.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Maven coordinates string.
+    ///
+    /// returns: `Result<Coordinates, CoordinatesError>`
+    pub fn parse(&mut self, coordinates: &str) -> Result<Coordinates, CoordinatesError> {
+        if let Some(result) = self.results.get(coordinates) {
+            return result.clone();
+        }
+
+        self.misses += 1;
+
+        let result = Coordinates::new(coordinates);
+        self.results.insert(coordinates.to_string(), result.clone());
+
+        result
+    }
+
+    /// Returns number of times input was not found in the cache and had to be parsed since the
+    /// cache was created or last [cleared][0].
+    ///
+    /// [0]: ParseCache::clear
+    ///
+    /// returns: usize
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Returns number of distinct inputs in the cache.
+    ///
+    /// returns: usize
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Checks whether cache is empty.
+    ///
+    /// returns: bool
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Removes all the cached results and resets the miss counter.
+    pub fn clear(&mut self) {
+        self.results.clear();
+        self.misses = 0;
+    }
+}
+
+/// Dependency on the artifact, as declared in the POM.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dependency {
+    /// Coordinates of the artifact.
+    pub coordinates: Coordinates,
+
+    /// Scope of the dependency, like `compile` or `test`. Maven assumes `compile` if not
+    /// specified.
+    pub scope: Option<String>,
+
+    /// Path to the artifact on the local file system for dependencies in the `system` scope.
+    ///
+    /// # Usage
+    ///
+    /// When present, artifact is never resolved from the repository, this path is used instead.
+    pub system_path: Option<String>,
+
+    /// Whether the dependency is excluded rather than included, like entries prefixed with `!`
+    /// in dependency lists.
+    pub excluded: bool,
+}
+
+impl Dependency {
+    /// Creates new dependency on the artifact without scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Coordinates of the artifact.
+    ///
+    /// returns: Dependency
+    pub fn new(coordinates: Coordinates) -> Self {
+        Self {
+            coordinates,
+            scope: None,
+            system_path: None,
+            excluded: false,
+        }
+    }
+
+    /// Creates new dependency from the entry of the dependency list, where excluded entries are
+    /// prefixed with `!`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry`: Dependency list entry, like `!$groupId:$artifactId:$version`. Rest of the entry
+    ///   is parsed as by [`Coordinates::new`].
+    ///
+    /// # Returns
+    ///
+    /// Result<Dependency, CoordinatesError>
+    ///
+    /// If coordinates are correct and parsed, this will be `Ok(Dependency)`, otherwise
+    /// `Err(CoordinatesError)` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::Dependency;
+    ///
+    /// let dependency = Dependency::from_entry("!org.example:badlib:1.0").unwrap();
+    /// dependency.excluded;
+    /// // => true
+    /// ```
+    pub fn from_entry(entry: &str) -> Result<Self, CoordinatesError> {
+        let entry = entry.trim();
+
+        let (entry, excluded) = match entry.strip_prefix(EXCLUSION_MARKER) {
+            Some(entry) => (entry.trim_start(), true),
+            None => (entry, false),
+        };
+
+        let mut dependency = Dependency::new(Coordinates::new(entry)?);
+        dependency.excluded = excluded;
+
+        Ok(dependency)
+    }
+
+    /// Resolves location of the artifact, which is the system path if one is set, or the URL
+    /// using given base Maven server address otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `maven_location`: Address of remote Maven server
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use maven_coordinates::{Coordinates, Dependency};
+    ///
+    /// let coords = Coordinates::new("com.sun:tools:1.8").unwrap();
+    /// let mut dependency = Dependency::new(coords);
+    /// dependency.scope = Some("system".to_string());
+    /// dependency.system_path = Some("/usr/lib/jvm/java-8/lib/tools.jar".to_string());
+    ///
+    /// dependency.resolve("https://repo.maven.apache.org/maven2/");
+    /// // => "/usr/lib/jvm/java-8/lib/tools.jar"
+    /// ```
+    pub fn resolve(&self, maven_location: &str) -> String {
+        match &self.system_path {
+            Some(system_path) => system_path.to_string(),
+            None => self.coordinates.resolve(maven_location),
+        }
+    }
+}
+
+/// Percent-encodes string, leaving only unreserved characters (letters, digits, `-`, `.`, `_`
+/// and `~`) as is.
+///
+/// # Arguments
+///
+/// * `decoded`: String to encode.
+///
+/// returns: String
+/// Maps packaging to the extension of the artifact file.
+///
+/// # Arguments
+///
+/// * `packaging`: Packaging to map.
+/// * `case_sensitive`: Whether known packagings are recognized only in lower case.
+///
+/// returns: &str
+fn packaging_extension(packaging: &str, case_sensitive: bool) -> &str {
+    let matches = |known: &str| {
+        if case_sensitive {
+            known == packaging
+        } else {
+            known.eq_ignore_ascii_case(packaging)
+        }
+    };
+
+    if JAR_PACKAGINGS.iter().any(|known| matches(known)) {
+        MAVEN_STANDARD_PACKAGING
+    } else if let Some(known) = KNOWN_PACKAGINGS.iter().find(|known| matches(known)) {
+        known
+    } else {
+        packaging
+    }
+}
+
+fn percent_encode(decoded: &str) -> String {
+    let mut encoded = String::with_capacity(decoded.len());
+
+    for byte in decoded.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded += &format!("%{:02X}", byte),
+        }
+    }
+
+    encoded
+}
+
+/// Decodes percent-encoded string.
+///
+/// # Arguments
+///
+/// * `encoded`: Percent-encoded string.
+///
+/// returns: `Option<String>`
+fn percent_decode(encoded: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut rest = encoded.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = tail.get(..2)?;
+            let hex = std::str::from_utf8(hex).ok()?;
+
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+/// Builds the plan for downloading artifacts, pairing each of the coordinates with its URL.
+///
+/// Coordinates resolving to the same file are only included once (first occurrence wins), and
+/// the plan is sorted by coordinates.
+///
+/// # Arguments
+///
+/// * `coords`: Coordinates to download.
+/// * `maven_location`: Address of remote Maven server
+///
+/// returns: `Vec<(Coordinates, String)>`
+pub fn build_download_plan(
+    coords: &[Coordinates],
+    maven_location: &str,
+) -> Vec<(Coordinates, String)> {
+    let mut seen = HashSet::new();
+
+    let mut plan: Vec<(Coordinates, String)> = coords
+        .iter()
+        .map(|c| (c.clone(), c.resolve(maven_location)))
+        .filter(|(_, url)| seen.insert(url.to_string()))
+        .collect();
+
+    plan.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    plan
+}
+
+/// Sorts coordinates paired with arbitrary values (like last access timestamps), primarily by
+/// coordinates ordering and then by the paired values.
+///
+/// # Arguments
+///
+/// * `items`: Pairs of coordinates and values to sort in place.
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::{sort_by_version_then, Coordinates};
+///
+/// let mut items = vec![
+///     (Coordinates::new("io.github.brawaru:artifact:1.10.0").unwrap(), 1),
+///     (Coordinates::new("io.github.brawaru:artifact:1.9.0").unwrap(), 2),
+/// ];
+///
+/// sort_by_version_then(&mut items);
+/// assert_eq!(items[0].1, 2);
+/// ```
+pub fn sort_by_version_then<T: Ord>(items: &mut [(Coordinates, T)]) {
+    items.sort_by(|(a, a_value), (b, b_value)| a.cmp(b).then_with(|| a_value.cmp(b_value)));
+}
+
+/// Resolves URLs for the artifacts under the new base Maven server address, in the order of
+/// coordinates, see [`Coordinates::resolve`].
+///
+/// # Arguments
+///
+/// * `coords`: Coordinates to resolve.
+/// * `new_base`: Address of the new remote Maven server
+///
+/// returns: `Vec<String>`
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::{rebase_all, Coordinates};
+///
+/// let coords = vec![Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap()];
+/// rebase_all(&coords, "https://mirror.example.com/maven/");
+/// // => ["https://mirror.example.com/maven/io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar"]
+/// ```
+pub fn rebase_all(coords: &[Coordinates], new_base: &str) -> Vec<String> {
+    resolve_iter(coords, new_base).collect()
+}
+
+/// Normalizes path separators, replacing Windows separators (`\`) with the default separator
+/// (`/`), so that paths recovered from mixed sources can be parsed with
+/// [`Coordinates::from_path`].
+///
+/// # Arguments
+///
+/// * `path`: Path with mixed separators.
+///
+/// returns: String
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::normalize_separators;
+///
+/// normalize_separators("io\\github/brawaru\\artifact");
+/// // => "io/github/brawaru/artifact"
+/// ```
+pub fn normalize_separators(path: &str) -> String {
+    path.replace('\\', &DEFAULT_SEPARATOR.to_string())
+}
+
+/// Compares two versions according to the Maven version ordering rules.
+///
+/// Versions are split into segments at dots, dashes and transitions between digits and letters,
+/// then segments are compared in order:
+///
+/// - Numeric segments are compared numerically, so `1.9` is older than `1.10`.
+/// - Qualifiers are ordered as `alpha` < `beta` < `milestone` < `rc` < `SNAPSHOT` < release
+///   < `sp`, case-insensitively, with `a`, `b`, `m`, `cr`, `ga`, `final` and `release` as
+///   aliases. Unknown qualifiers are newer than all of these and compared lexically.
+/// - Numeric segments are newer than qualifiers.
+/// - Missing segments are considered `0` or release, so `1.0` equals `1.0.0`, `1.0-alpha` is
+///   older than `1.0` and `1.0-sp` is newer.
+///
+/// This is a simplified version of Maven's rules, which does not distinguish between dot and
+/// dash separators.
+///
+/// # Arguments
+///
+/// * `a`: First version.
+/// * `b`: Second version.
+///
+/// returns: Ordering
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::compare_versions;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(compare_versions("1.9", "1.10"), Ordering::Less);
+/// assert_eq!(compare_versions("1.0-SNAPSHOT", "1.0"), Ordering::Less);
+/// ```
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let a = version_segments(a);
+    let b = version_segments(b);
+
+    for index in 0..a.len().max(b.len()) {
+        let ordering = match (a.get(index), b.get(index)) {
+            (Some(a), Some(b)) => compare_version_segments(a, b),
+            (Some(a), None) => compare_version_segments(a, ""),
+            (None, Some(b)) => compare_version_segments("", b),
+            (None, None) => Ordering::Equal,
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Splits version into lower-case segments at dots, dashes and transitions between digits and
+/// letters.
+///
+/// # Arguments
+///
+/// * `version`: Version to split.
+///
+/// returns: `Vec<String>`
+fn version_segments(version: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut segment = String::new();
+
+    for character in version.to_lowercase().chars() {
+        let is_separator = character == '.' || character == '-';
+        let is_transition = segment
+            .chars()
+            .last()
+            .is_some_and(|last| last.is_ascii_digit() != character.is_ascii_digit());
+
+        if (is_separator || is_transition) && !segment.is_empty() {
+            segments.push(std::mem::take(&mut segment));
+        }
+
+        if !is_separator {
+            segment.push(character);
+        }
+    }
+
+    if !segment.is_empty() {
+        segments.push(segment);
+    }
+
+    segments
+}
+
+/// Compares two version segments, where empty segment stands for the missing one.
+///
+/// # Arguments
+///
+/// * `a`: First segment.
+/// * `b`: Second segment.
+///
+/// returns: Ordering
+fn compare_version_segments(a: &str, b: &str) -> Ordering {
+    let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+    match (is_number(a), is_number(b)) {
+        (true, true) => {
+            let (a, b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+
+            a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+        }
+        (true, false) if b.is_empty() => compare_version_segments(a, "0"),
+        (false, true) if a.is_empty() => compare_version_segments("0", b),
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => {
+            let rank = |s: &str| {
+                QUALIFIER_RANKS
+                    .iter()
+                    .position(|aliases| aliases.contains(&s))
+            };
+
+            match (rank(a), rank(b)) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a.cmp(b),
+            }
+        }
+    }
+}
+
+/// Lazily resolves URLs for the artifacts, one at a time, in the order of coordinates.
+///
+/// Unlike [`build_download_plan`], URLs are neither collected, deduplicated nor sorted, so
+/// memory use stays flat for large sets of coordinates.
+///
+/// # Arguments
+///
+/// * `coords`: Coordinates to resolve.
+/// * `maven_location`: Address of remote Maven server
+///
+/// returns: `impl Iterator<Item = String>`
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::{resolve_iter, Coordinates};
+///
+/// let coords = vec![Coordinates::new("io.github.brawaru:artifact:1.0.0").unwrap()];
+/// let mut urls = resolve_iter(&coords, "https://brawaru.github.io/maven/");
+/// urls.next();
+/// // => Some("https://brawaru.github.io/maven/io/github/brawaru/artifact/1.0.0/artifact-1.0.0.jar")
+/// ```
+pub fn resolve_iter<'a>(
+    coords: &'a [Coordinates],
+    maven_location: &'a str,
+) -> impl Iterator<Item = String> + 'a {
+    coords.iter().map(move |c| c.resolve(maven_location))
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Coordinates {
+    /// Serializes coordinates as the coordinates string, like `$groupId:$artifactId:$version`.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Coordinates {
+    /// Deserializes coordinates from the coordinates string, see [`Coordinates::new`].
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CoordinatesVisitor;
+
+        impl serde::de::Visitor<'_> for CoordinatesVisitor {
+            type Value = Coordinates;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("Maven coordinates string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Coordinates, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CoordinatesVisitor)
+    }
+}
+
+/// Builder of the coordinates struct, for constructing coordinates programmatically.
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::CoordinatesBuilder;
+///
+/// let artifact = CoordinatesBuilder::new()
+///     .group_id("io.github.brawaru")
+///     .artifact_id("artifact")
+///     .version("1.0.0")
+///     .classifier("sources")
+///     .build()
+///     .unwrap();
+///
+/// artifact.to_string();
+/// // => "io.github.brawaru:artifact:1.0.0:jar:sources"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CoordinatesBuilder {
+    group_id: Option<String>,
+    artifact_id: Option<String>,
+    version: Option<String>,
+    version_label: Option<String>,
+    packaging: Option<String>,
+    classifier: Option<String>,
+}
+
+impl CoordinatesBuilder {
+    /// Creates new builder with no fields set.
+    ///
+    /// returns: CoordinatesBuilder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets group ID.
+    pub fn group_id<S: Into<String>>(mut self, group_id: S) -> Self {
+        self.group_id = Some(group_id.into());
+        self
+    }
+
+    /// Sets artifact ID.
+    pub fn artifact_id<S: Into<String>>(mut self, artifact_id: S) -> Self {
+        self.artifact_id = Some(artifact_id.into());
+        self
+    }
+
+    /// Sets version. Unless version label is set, version is split into version and label
+    /// (and build metadata) the same way as [`Coordinates::new`] does it.
+    pub fn version<S: Into<String>>(mut self, version: S) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Sets version label, in which case version is used as is.
+    pub fn version_label<S: Into<String>>(mut self, version_label: S) -> Self {
+        self.version_label = Some(version_label.into());
+        self
+    }
+
+    /// Sets packaging, standard packaging (`jar`) is used if not set.
+    pub fn packaging<S: Into<String>>(mut self, packaging: S) -> Self {
+        self.packaging = Some(packaging.into());
+        self
+    }
+
+    /// Sets classifier.
+    pub fn classifier<S: Into<String>>(mut self, classifier: S) -> Self {
+        self.classifier = Some(classifier.into());
+        self
+    }
+
+    /// Builds the coordinates struct.
+    ///
+    /// # Returns
+    ///
+    /// Result<Coordinates, CoordinatesError>
+    ///
+    /// If group ID, artifact ID and version are set and not empty, this will be
+    /// `Ok(Coordinates)`, otherwise `Err(CoordinatesError)` naming the missing or empty field
+    /// will be returned.
+    pub fn build(self) -> Result<Coordinates, CoordinatesError> {
+        let mut coordinates = Coordinates::from_required_parts(
+            self.group_id.as_deref(),
+            self.artifact_id.as_deref(),
+            self.version.as_deref(),
+            self.packaging.as_deref(),
+            self.classifier.as_deref(),
+        )?;
+
+        if let (Some(version), Some(version_label)) = (self.version, self.version_label) {
+            coordinates.version = version;
+            coordinates.version_label = Some(version_label);
+            coordinates.build_metadata = None;
+        }
+
+        Ok(coordinates)
+    }
+}
+
+/// Coordinates borrowing all of their components from the parsed string.
+///
+/// Unlike [`Coordinates`], parsing these does not allocate, which is useful for inspecting many
+/// coordinates transiently (like when scanning a large lockfile). Use [`into_owned`][0] to
+/// promote them to [`Coordinates`].
+///
+/// [0]: CoordinatesRef::into_owned
+///
+/// # Examples
+///
+/// ```
+/// use maven_coordinates::CoordinatesRef;
+///
+/// let artifact = CoordinatesRef::parse("io.github.brawaru:artifact:1.0.0-SNAPSHOT:jar:sources").unwrap();
+///
+/// assert_eq!(artifact.artifact_id, "artifact");
+/// assert_eq!(artifact.full_version(), "1.0.0-SNAPSHOT");
+/// assert_eq!(artifact.into_owned().file_name(), "artifact-1.0.0-SNAPSHOT-sources.jar");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CoordinatesRef<'a> {
+    /// Group ID, see [`Coordinates::group_id`].
+    pub group_id: &'a str,
+
+    /// Artifact ID, see [`Coordinates::artifact_id`].
+    pub artifact_id: &'a str,
+
+    /// Version without the label and build metadata, see [`Coordinates::version`].
+    pub version: &'a str,
+
+    /// Version label, see [`Coordinates::version_label`].
+    pub version_label: Option<&'a str>,
+
+    /// Build metadata, see [`Coordinates::build_metadata`].
+    pub build_metadata: Option<&'a str>,
+
+    /// Packaging, see [`Coordinates::packaging`].
+    pub packaging: &'a str,
+
+    /// Classifier, see [`Coordinates::classifier`].
+    pub classifier: Option<&'a str>,
+
+    /// Complete version as it appears in the parsed string.
+    full_version: &'a str,
+
+    /// Whether packaging was explicitly specified in the parsed string.
+    explicit_packaging: bool,
+}
+
+impl<'a> CoordinatesRef<'a> {
+    /// Parses coordinates string without allocating, see [`Coordinates::new`] for the format.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: Maven coordinates string, which follows the format:
+    ///   `[maven:]$groupId:$artifactId:$version[:$packaging[:$classifier]]`.
+    ///
+    /// # Returns
+    ///
+    /// Result<CoordinatesRef, CoordinatesError>
+    ///
+    /// If coordinates string is correct and parsed, this will be `Ok(CoordinatesRef)`, otherwise
+    /// `Err(CoordinatesError)` will be returned, same as for [`Coordinates::new`].
+    pub fn parse(coordinates: &'a str) -> Result<Self, CoordinatesError> {
+        let input = coordinates;
+        let coordinates = Coordinates::strip_maven_scheme(coordinates);
+
+        if coordinates.is_empty() {
+            return Err(CoordinatesError::MissingGroupId);
+        }
+
+        // $groupId:$packageId:$version-$qualifier:$packaging:$classifier
+
+        let mut parts = coordinates.split(MAVEN_COORDINATES_SPLITTER);
+        let (group_id, artifact_id, version) = (parts.next(), parts.next(), parts.next());
+        let (packaging, classifier) = (parts.next(), parts.next());
+
+        if parts.next().is_some() {
+            return Err(CoordinatesError::TooManyComponents(input.to_string()));
+        }
+
+        let group_id = group_id.ok_or(CoordinatesError::MissingGroupId)?;
+        let artifact_id = artifact_id.ok_or(CoordinatesError::MissingArtifactId)?;
+        let full_version = version.ok_or(CoordinatesError::MissingVersion)?;
+
+        let components = [
+            ("group ID", group_id),
+            ("artifact ID", artifact_id),
+            ("version", full_version),
+        ];
+
+        if let Some((component, _)) = components.iter().find(|(_, value)| value.is_empty()) {
+            return Err(CoordinatesError::EmptyComponent(component));
+        }
+
+        let (version, build_metadata) = Coordinates::split_build_metadata(full_version);
+        let (version, version_label) = Coordinates::split_version(version);
+
+        Ok(CoordinatesRef {
+            group_id,
+            artifact_id,
+            version,
+            version_label,
+            build_metadata,
+            packaging: packaging.unwrap_or(MAVEN_STANDARD_PACKAGING),
+            classifier,
+            full_version,
+            explicit_packaging: packaging.is_some(),
+        })
+    }
+
+    /// Returns complete version (including the label and build metadata), see
+    /// [`Coordinates::full_version`].
+    ///
+    /// returns: &str
+    pub fn full_version(&self) -> &'a str {
+        self.full_version
+    }
+
+    /// Returns extension of the artifact file, see [`Coordinates::file_extension`].
+    ///
+    /// returns: &str
+    pub fn file_extension(&self) -> &'a str {
+        self.file_extension_with_case(true)
+    }
+
+    /// Returns extension of the artifact file, optionally recognizing known packagings
+    /// regardless of their case, see [`Coordinates::file_extension_with_case`].
+    ///
+    /// returns: &str
+    pub fn file_extension_with_case(&self, case_sensitive: bool) -> &'a str {
+        packaging_extension(self.packaging, case_sensitive)
+    }
+
+    /// Returns base file name for this artifact, see [`Coordinates::file_basename`].
+    ///
+    /// returns: String
+    pub fn file_basename(&self) -> String {
+        let mut file_name = self.artifact_id.to_string();
+
+        file_name += FILENAME_SPLITTER;
+        file_name += self.full_version;
+
+        if let Some(classifier) = self.classifier {
+            file_name += FILENAME_SPLITTER;
+            file_name += classifier;
+        }
+
+        file_name
+    }
+
+    /// Returns complete file name for this artifact, see [`Coordinates::file_name`].
+    ///
+    /// returns: String
+    pub fn file_name(&self) -> String {
+        let mut file_name = self.file_basename();
+
+        file_name += EXTENSION_SPLITTER;
+        file_name += self.file_extension();
+
+        file_name
+    }
+
+    /// Converts borrowed coordinates to the owned [`Coordinates`].
+    ///
+    /// returns: Coordinates
+    pub fn into_owned(self) -> Coordinates {
+        Coordinates {
+            group_id: self.group_id.to_string(),
+            artifact_id: self.artifact_id.to_string(),
+            version: self.version.to_string(),
+            version_label: self.version_label.map(|label| label.to_string()),
+            build_metadata: self.build_metadata.map(|metadata| metadata.to_string()),
+            packaging: self.packaging.to_string(),
+            classifier: self.classifier.map(|classifier| classifier.to_string()),
+            explicit_packaging: self.explicit_packaging,
+        }
+    }
+}
+
+impl<'a> From<CoordinatesRef<'a>> for Coordinates {
+    fn from(coordinates: CoordinatesRef<'a>) -> Self {
+        coordinates.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "checksum")]
+    use crate::ArtifactChecksum;
+    use crate::{
+        build_download_plan, compare_versions, find_snapshots, normalize_separators,
+        parse_gradle_lockfile, rebase_all, resolve_iter, sort_by_version_then, CachedCoordinates,
+        ChecksumAlgorithm, Coordinates, CoordinatesBuilder, CoordinatesError, CoordinatesRef,
+        Dependency, ParseCache,
+    };
+    use std::cmp::Ordering;
+    use std::collections::{BTreeMap, HashSet};
+    use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+
+    #[test]
     fn it_parses_all_values() {
         let result = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier")
             .expect("Parsing failed");
 
-        assert_eq!(result.group_id, "id.group");
-        assert_eq!(result.artifact_id, "artifact-id");
+        assert_eq!(result.group_id, "id.group");
+        assert_eq!(result.artifact_id, "artifact-id");
+        assert_eq!(result.version, "1.0.0");
+        assert_eq!(
+            result.version_label.expect("Version label not parsed"),
+            "SNAPSHOT"
+        );
+        assert_eq!(result.packaging, "ext");
+        assert_eq!(
+            result.classifier.expect("Classifier not parsed"),
+            "classifier"
+        );
+    }
+
+    #[test]
+    fn it_parses_partial_values() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").expect("Parsing failed");
+
+        assert_eq!(result.group_id, "id.group");
+        assert_eq!(result.artifact_id, "artifact-id");
+        assert_eq!(result.version, "1.0.0");
+        assert!(result.version_label.is_none());
+        assert_eq!(result.packaging, crate::MAVEN_STANDARD_PACKAGING);
+        assert!(result.classifier.is_none());
+    }
+
+    #[test]
+    fn it_requires_general_data() {
+        let result = Coordinates::new("id.group:artifact-id");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingVersion);
+    }
+
+    #[test]
+    fn it_resolves_file_name_all() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+
+        assert_eq!(
+            result.file_basename(),
+            "artifact-id-1.0.0-SNAPSHOT-classifier"
+        );
+
+        assert_eq!(
+            result.file_name(),
+            "artifact-id-1.0.0-SNAPSHOT-classifier.ext"
+        );
+    }
+
+    #[test]
+    fn it_resolves_file_name_partial() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(result.file_basename(), "artifact-id-1.0.0");
+        assert_eq!(result.file_name(), "artifact-id-1.0.0.jar");
+    }
+
+    #[test]
+    fn it_resolves_path() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+
+        assert_eq!(
+            result.to_path(),
+            "id/group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-SNAPSHOT-classifier.ext"
+        );
+    }
+
+    #[test]
+    fn it_bumps_patch_version() {
+        let result = Coordinates::new("id.group:artifact-id:1.2.3").unwrap();
+
+        assert_eq!(result.bump_patch().expect("Bump failed").version, "1.2.4");
+    }
+
+    #[test]
+    fn it_does_not_bump_non_semver_version() {
+        let result = Coordinates::new("id.group:artifact-id:latest").unwrap();
+
+        assert!(result.bump_patch().is_none());
+    }
+
+    #[test]
+    fn it_parses_gradle_lockfile() {
+        let lockfile = "\
+# This is a Gradle generated file for dependency locking.
+# Manual edits can break the build and are not advised.
+# This file is expected to be part of source control.
+com.google.guava:guava:31.1-jre=compileClasspath,runtimeClasspath
+org.slf4j:slf4j-api:2.0.7=runtimeClasspath
+empty=annotationProcessor
+";
+
+        let result = parse_gradle_lockfile(lockfile);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].to_string(), "com.google.guava:guava:31.1-jre");
+        assert_eq!(result[1].to_string(), "org.slf4j:slf4j-api:2.0.7");
+    }
+
+    #[test]
+    fn it_converts_to_field_map() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+        let map = result.to_field_map();
+
+        assert_eq!(map.len(), 5);
+        assert_eq!(map["groupId"], "id.group");
+        assert_eq!(map["artifactId"], "artifact-id");
+        assert_eq!(map["version"], "1.0.0-SNAPSHOT");
+        assert_eq!(map["type"], "ext");
+        assert_eq!(map["classifier"], "classifier");
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        let map = result.to_field_map();
+
+        assert_eq!(map.len(), 4);
+        assert!(!map.contains_key("classifier"));
+    }
+
+    #[test]
+    fn it_finds_snapshots() {
+        let coords = vec![
+            Coordinates::new("id.group:release:1.0.0").unwrap(),
+            Coordinates::new("id.group:snapshot:1.0.0-SNAPSHOT").unwrap(),
+            Coordinates::new("id.group:candidate:1.0.0-rc1").unwrap(),
+            Coordinates::new("id.group:lowercase:2.0-snapshot").unwrap(),
+        ];
+
+        let result = find_snapshots(&coords);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].artifact_id, "snapshot");
+        assert_eq!(result[1].artifact_id, "lowercase");
+    }
+
+    #[test]
+    fn it_parses_version_last_all_values() {
+        let result = Coordinates::new_version_last("id.group:artifact-id:ext:classifier:1.0.0")
+            .expect("Parsing failed");
+
+        assert_eq!(result.group_id, "id.group");
+        assert_eq!(result.artifact_id, "artifact-id");
+        assert_eq!(result.version, "1.0.0");
+        assert_eq!(result.packaging, "ext");
+        assert_eq!(
+            result.classifier.expect("Classifier not parsed"),
+            "classifier"
+        );
+    }
+
+    #[test]
+    fn it_parses_version_last_partial_values() {
+        let result =
+            Coordinates::new_version_last("id.group:artifact-id:1.0.0").expect("Parsing failed");
+
+        assert_eq!(
+            result,
+            Coordinates::new("id.group:artifact-id:1.0.0").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_formats_template() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+
+        assert_eq!(
+            result.format_template("{group}/{artifact}/{version}/{file} [{label}] {unknown} {"),
+            "id.group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-SNAPSHOT-classifier.ext [SNAPSHOT] {unknown} {"
+        );
+
+        assert_eq!(
+            result.format_template("{packaging}:{classifier} -> {path}"),
+            "ext:classifier -> id/group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-SNAPSHOT-classifier.ext"
+        );
+    }
+
+    #[test]
+    fn it_converts_to_gav() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+
+        assert_eq!(result.to_gav(), "id.group:artifact-id:1.0.0-SNAPSHOT");
+    }
+
+    #[test]
+    fn it_rejects_whitespace_only_components() {
+        let result = Coordinates::new("id.group:   :1.0.0").expect("Parsing failed");
+
+        assert_eq!(
+            result.validate(),
+            Err(CoordinatesError::EmptyComponent("artifact ID"))
+        );
+
+        let result = Coordinates::new_strict("id.group:   :1.0.0");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::EmptyComponent("artifact ID"));
+
+        assert!(Coordinates::new_strict("id.group:artifact-id:1.0.0").is_ok());
+    }
+
+    #[test]
+    fn it_parses_pom_properties() {
+        let properties = "\
+#Generated by Maven
+#Tue Oct 10 12:00:00 UTC 2023
+artifactId=commons-lang3
+groupId=org.apache.commons
+version=3.13.0
+";
+
+        let result = Coordinates::from_pom_properties(properties).expect("Parsing failed");
+
+        assert_eq!(
+            result.to_string(),
+            "org.apache.commons:commons-lang3:3.13.0"
+        );
+    }
+
+    #[test]
+    fn it_requires_pom_properties_general_data() {
+        let result = Coordinates::from_pom_properties("groupId=id.group\nversion=1.0.0\n");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingArtifactId);
+    }
+
+    #[test]
+    fn it_converts_to_pom_properties() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier")
+            .unwrap()
+            .to_pom_properties();
+
+        assert_eq!(
+            result.lines().collect::<Vec<_>>(),
+            vec![
+                "groupId=id.group",
+                "artifactId=artifact-id",
+                "version=1.0.0-SNAPSHOT"
+            ]
+        );
+
+        let parsed = Coordinates::from_pom_properties(&result).expect("Parsing failed");
+
+        assert_eq!(parsed.to_gav(), "id.group:artifact-id:1.0.0-SNAPSHOT");
+    }
+
+    #[test]
+    fn it_parses_build_metadata() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-rc1+build.5").expect("Parsing failed");
+
+        assert_eq!(result.version, "1.0.0");
+        assert_eq!(result.version_label.as_deref(), Some("rc1"));
+        assert_eq!(result.build_metadata.as_deref(), Some("build.5"));
+        assert_eq!(result.full_version(), "1.0.0-rc1+build.5");
+        assert_eq!(result.file_name(), "artifact-id-1.0.0-rc1+build.5.jar");
+
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0+build.5").expect("Parsing failed");
+
+        assert_eq!(result.version, "1.0.0");
+        assert!(result.version_label.is_none());
+        assert_eq!(result.build_metadata.as_deref(), Some("build.5"));
+    }
+
+    #[test]
+    fn it_compares_versions_ignoring_build_metadata() {
+        let build_5 = Coordinates::new("id.group:artifact-id:1.0.0+build.5").unwrap();
+        let build_6 = Coordinates::new("id.group:artifact-id:1.0.0+build.6").unwrap();
+        let plain = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        let other = Coordinates::new("id.group:artifact-id:1.0.1+build.5").unwrap();
+
+        assert!(build_5.same_version(&build_6));
+        assert!(build_5.same_version(&plain));
+        assert!(!build_5.same_version(&other));
+    }
+
+    #[test]
+    fn it_resolves_last_updated_marker_path() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(
+            result.last_updated_marker_path('/'),
+            "id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar.lastUpdated"
+        );
+    }
+
+    #[test]
+    fn it_resolves_artifactory_api_url() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(
+            result.resolve_artifactory_api("https://example.jfrog.io/artifactory/", "libs-release"),
+            "https://example.jfrog.io/artifactory/api/storage/libs-release/id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar"
+        );
+    }
+
+    #[test]
+    fn it_resolves_shaded_artifact() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0")
+            .unwrap()
+            .shaded();
+
+        assert_eq!(result.file_name(), "artifact-id-1.0.0-shaded.jar");
+    }
+
+    #[test]
+    fn it_parses_kotlin_dsl_string_notation() {
+        let result =
+            Coordinates::from_kotlin_dsl(r#"    implementation("id.group:artifact-id:1.0.0")"#)
+                .expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0");
+    }
+
+    #[test]
+    fn it_parses_kotlin_dsl_named_arguments() {
+        let result = Coordinates::from_kotlin_dsl(
+            r#"testImplementation(group = "id.group", name = "artifact-id", version = "1.0.0", classifier = "tests")"#,
+        )
+        .expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0:jar:tests");
+
+        let result = Coordinates::from_kotlin_dsl(r#"implementation(group = "id.group")"#);
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingArtifactId);
+    }
+
+    #[test]
+    fn it_resolves_path_parts() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+        let (directory, file_name) = result.to_path_parts('\\');
+
+        assert_eq!(directory, "id\\group\\artifact-id\\1.0.0-SNAPSHOT");
+        assert_eq!(file_name, "artifact-id-1.0.0-SNAPSHOT-classifier.ext");
+    }
+
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn it_verifies_checksum() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        let checksum = ArtifactChecksum::new(
+            coords.clone(),
+            ChecksumAlgorithm::Sha1,
+            "AAF4C61DDCC5E8A2DABEDE0F3B482CD9AEA9434D  artifact-id-1.0.0.jar\n",
+        );
+
+        assert!(checksum.verify(b"hello"));
+        assert!(!checksum.verify(b"world"));
+
+        let checksum = ArtifactChecksum::new(
+            coords,
+            ChecksumAlgorithm::Md5,
+            "5d41402abc4b2a76b9719d911017c592",
+        );
+
+        assert!(checksum.verify(b"hello"));
+    }
+
+    #[test]
+    fn it_parses_quoted_components() {
+        let result =
+            Coordinates::new_quoted(r#"id.group:artifact-id:"1:0":ext"#).expect("Parsing failed");
+
+        assert_eq!(result.group_id, "id.group");
+        assert_eq!(result.artifact_id, "artifact-id");
+        assert_eq!(result.version, "1:0");
+        assert_eq!(result.packaging, "ext");
+        assert!(result.classifier.is_none());
+    }
+
+    #[test]
+    fn it_rejects_unbalanced_quotes() {
+        let result = Coordinates::new_quoted(r#"id.group:artifact-id:"1:0:ext"#);
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::UnbalancedQuotes(r#"id.group:artifact-id:"1:0:ext"#.to_string())
+        );
+    }
+
+    #[test]
+    fn it_strips_path_prefix() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(
+            result.strip_prefix_path("id/group/", '/').as_deref(),
+            Some("artifact-id/1.0.0/artifact-id-1.0.0.jar")
+        );
+        assert_eq!(
+            result.strip_prefix_path("id", '/').as_deref(),
+            Some("group/artifact-id/1.0.0/artifact-id-1.0.0.jar")
+        );
+        assert!(result.strip_prefix_path("id/gro", '/').is_none());
+        assert!(result.strip_prefix_path("other", '/').is_none());
+    }
+
+    #[test]
+    fn it_parses_dependency_tree_line() {
+        let result =
+            Coordinates::from_dependency_tree_line("[INFO] |  +- org.example:lib:jar:1.0:compile")
+                .expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "org.example:lib:1.0");
+
+        let result = Coordinates::from_dependency_tree_line(
+            "|  \\- org.example:natives:jar:linux:1.0:runtime (optional)",
+        )
+        .expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "org.example:natives:1.0:jar:linux");
+
+        let result = Coordinates::from_dependency_tree_line("org.example:app:war:2.0-SNAPSHOT")
+            .expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "org.example:app:2.0-SNAPSHOT:war");
+    }
+
+    #[test]
+    fn it_caches_computed_values() {
+        let coords =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+        let mut cached = CachedCoordinates::new(coords.clone());
+
+        assert_eq!(cached.to_path(), coords.to_path());
+        assert_eq!(cached.as_str(), coords.to_string());
+
+        cached.coordinates_mut().version = "2.0.0".to_string();
+
+        assert_eq!(
+            cached.to_path(),
+            "id/group/artifact-id/2.0.0-SNAPSHOT/artifact-id-2.0.0-SNAPSHOT-classifier.ext"
+        );
+        assert_eq!(
+            cached.as_str(),
+            "id.group:artifact-id:2.0.0-SNAPSHOT:ext:classifier"
+        );
+    }
+
+    #[test]
+    fn it_resolves_index_links() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(
+            result.index_links(),
+            vec![
+                "artifact-id-1.0.0.jar",
+                "artifact-id-1.0.0.jar.md5",
+                "artifact-id-1.0.0.jar.sha1",
+                "artifact-id-1.0.0.pom",
+                "artifact-id-1.0.0.pom.md5",
+                "artifact-id-1.0.0.pom.sha1",
+            ]
+        );
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0:pom").unwrap();
+
+        assert_eq!(
+            result.index_links(),
+            vec![
+                "artifact-id-1.0.0.pom",
+                "artifact-id-1.0.0.pom.md5",
+                "artifact-id-1.0.0.pom.sha1",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_matches_file_names() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert!(result.matches_file_name("artifact-id-1.0.0.jar"));
+        assert!(!result.matches_file_name("artifact-id-1.0.1.jar"));
+        assert!(!result.matches_file_name("artifact-id-1.0.0-20231001.123456-3.jar"));
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:jar:sources").unwrap();
+
+        assert!(result.matches_file_name("artifact-id-1.0.0-SNAPSHOT-sources.jar"));
+        assert!(result.matches_file_name("artifact-id-1.0.0-20231001.123456-3-sources.jar"));
+        assert!(!result.matches_file_name("artifact-id-1.0.0-20231001.123456-3.jar"));
+        assert!(!result.matches_file_name("artifact-id-1.0.0-2023.123456-3-sources.jar"));
+        assert!(!result.matches_file_name("other-1.0.0-20231001.123456-3-sources.jar"));
+    }
+
+    #[test]
+    fn it_resolves_system_dependency() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        let mut dependency = Dependency::new(coords);
+
+        assert_eq!(
+            dependency.resolve("https://example.com/maven"),
+            "https://example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar"
+        );
+
+        dependency.scope = Some("system".to_string());
+        dependency.system_path = Some("/opt/libs/artifact-id.jar".to_string());
+
+        assert_eq!(
+            dependency.resolve("https://example.com/maven"),
+            "/opt/libs/artifact-id.jar"
+        );
+    }
+
+    #[test]
+    fn it_extracts_classifiers_from_listing() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        let listing = [
+            "artifact-id-1.0.0.jar",
+            "artifact-id-1.0.0.jar.sha1",
+            "artifact-id-1.0.0.pom",
+            "artifact-id-1.0.0-sources.jar",
+            "artifact-id-1.0.0-javadoc.jar",
+            "artifact-id-1.0.0-javadoc.jar.md5",
+            "artifact-id-1.0.01.jar",
+            "maven-metadata.xml",
+        ];
+
+        assert_eq!(
+            result.classifiers_from_listing(&listing),
+            vec![
+                None,
+                Some("sources".to_string()),
+                Some("javadoc".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn it_parses_spdx_external_ref() {
+        let result = Coordinates::from_spdx_external_ref(
+            "ExternalRef: PACKAGE-MANAGER purl pkg:maven/org.apache.commons/commons-lang3@3.12.0",
+        )
+        .expect("Parsing failed");
+
+        assert_eq!(
+            result.to_string(),
+            "org.apache.commons:commons-lang3:3.12.0"
+        );
+
+        let result = Coordinates::from_spdx_external_ref(
+            "pkg:maven/id.group/artifact-id@1.0.0%2Bbuild.5?classifier=sources&type=jar",
+        )
+        .expect("Parsing failed");
+
+        assert_eq!(
+            result.to_string(),
+            "id.group:artifact-id:1.0.0+build.5:jar:sources"
+        );
+
+        let result = Coordinates::from_spdx_external_ref("pkg:npm/left-pad@1.3.0");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::InvalidNotation("pkg:npm/left-pad@1.3.0".to_string())
+        );
+    }
+
+    #[test]
+    fn it_resolves_common_path_prefix() {
+        let first = Coordinates::new("id.group.first:artifact-id:1.0.0").unwrap();
+        let second = Coordinates::new("id.group.second:artifact-id:1.0.0").unwrap();
+        let other = Coordinates::new("other.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(first.common_path_prefix(&second, '/'), "id/group");
+        assert_eq!(first.common_path_prefix(&other, '/'), "");
+        assert_eq!(
+            first.common_path_prefix(&first.pom(), '/'),
+            "id/group/first/artifact-id/1.0.0"
+        );
+    }
+
+    #[test]
+    fn it_canonicalizes_equivalent_coordinates() {
+        let first = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT").unwrap();
+        let second = Coordinates::new(" ID.Group :Artifact-ID:1.0.0-SNAPSHOT :JAR: ").unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(first.canonicalize(), second.canonicalize());
+        assert_eq!(
+            second.to_canonical_string(),
+            "id.group:artifact-id:1.0.0-SNAPSHOT"
+        );
+    }
+
+    #[test]
+    fn it_resolves_pom_checksum() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+        let checksum = result.pom_checksum(ChecksumAlgorithm::Sha1);
+
+        assert_eq!(checksum, result.pom().checksum(ChecksumAlgorithm::Sha1));
+        assert_eq!(checksum.packaging, "pom.sha1");
+        assert!(checksum.classifier.is_none());
+        assert_eq!(
+            checksum.resolve("https://example.com/maven/"),
+            "https://example.com/maven/id/group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-SNAPSHOT.pom.sha1"
+        );
+        assert_eq!(
+            result.pom_checksum(ChecksumAlgorithm::Md5).file_name(),
+            "artifact-id-1.0.0-SNAPSHOT.pom.md5"
+        );
+    }
+
+    #[test]
+    fn it_swaps_packaging_and_classifier_leniently() {
+        let result = Coordinates::new_lenient_swap("id.group:artifact-id:1.0.0:sources:jar")
+            .expect("Parsing failed");
+
+        assert_eq!(result.packaging, "jar");
+        assert_eq!(result.classifier.as_deref(), Some("sources"));
+
+        let result = Coordinates::new_lenient_swap("id.group:artifact-id:1.0.0:jar:sources")
+            .expect("Parsing failed");
+
+        assert_eq!(result.packaging, "jar");
+        assert_eq!(result.classifier.as_deref(), Some("sources"));
+
+        let result = Coordinates::new_lenient_swap("id.group:artifact-id:1.0.0:custom:jar")
+            .expect("Parsing failed");
+
+        assert_eq!(result.packaging, "custom");
+        assert_eq!(result.classifier.as_deref(), Some("jar"));
+    }
+
+    #[test]
+    fn it_builds_download_plan() {
+        let coords = vec![
+            Coordinates::new("id.group:second:1.0.0").unwrap(),
+            Coordinates::new("id.group:first:1.0.0:jar").unwrap(),
+            Coordinates::new("id.group:second:1.0.0:jar").unwrap(),
+            Coordinates::new("id.group:first:1.0.0:pom").unwrap(),
+        ];
+
+        let result = build_download_plan(&coords, "https://example.com/maven");
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|(_, url)| url.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "https://example.com/maven/id/group/first/1.0.0/first-1.0.0.jar",
+                "https://example.com/maven/id/group/first/1.0.0/first-1.0.0.pom",
+                "https://example.com/maven/id/group/second/1.0.0/second-1.0.0.jar",
+            ]
+        );
+        assert_eq!(result[0].0, coords[1]);
+    }
+
+    #[test]
+    fn it_strips_maven_scheme() {
+        let result = Coordinates::new("maven:id.group:artifact-id:1.0.0").expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0");
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0");
+
+        let result = Coordinates::new("maven:junit:junit:4.12").expect("Parsing failed");
+
+        assert_eq!(result.group_id, "junit");
+        assert_eq!(result.artifact_id, "junit");
+        assert_eq!(result.version, "4.12");
+        assert_eq!(result.packaging, "jar");
+
+        let result = Coordinates::new("maven:maven-model:3.0").expect("Parsing failed");
+
+        assert_eq!(result.group_id, "maven");
+        assert_eq!(result.artifact_id, "maven-model");
+        assert_eq!(result.version, "3.0");
+
+        let result = Coordinates::new("maven:maven-model:3.0:pom").expect("Parsing failed");
+
+        assert_eq!(result.group_id, "maven");
+        assert_eq!(result.artifact_id, "maven-model");
+        assert_eq!(result.version, "3.0");
+        assert_eq!(result.packaging, "pom");
+
+        let result = Coordinates::new("maven:maven-model:3.0:jar:sources").expect("Parsing failed");
+
+        assert_eq!(result.group_id, "maven");
+        assert_eq!(result.artifact_id, "maven-model");
+        assert_eq!(result.version, "3.0");
+        assert_eq!(result.packaging, "jar");
+        assert_eq!(result.classifier, Some("sources".to_string()));
+
+        let result = Coordinates::new("maven:id.group:artifact-id:1.0.0:jar:sources")
+            .expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0:jar:sources");
+    }
+
+    #[test]
+    fn it_rejects_unknown_schemes_strictly() {
+        let result =
+            Coordinates::new_strict("maven:id.group:artifact-id:1.0.0").expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0");
+
+        let result = Coordinates::new_strict("ivy://id.group/artifact-id/1.0.0");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::UnsupportedScheme("ivy".to_string())
+        );
+
+        let result = Coordinates::new_strict("eclipse:org.eclipse.jdt.core:3.1.1:jar")
+            .expect("Parsing failed");
+
+        assert_eq!(result.group_id, "eclipse");
+        assert_eq!(result.artifact_id, "org.eclipse.jdt.core");
+        assert_eq!(result.version, "3.1.1");
+    }
+
+    #[test]
+    fn it_builds_verification_map() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+        let map = result.verification_map(&[ChecksumAlgorithm::Sha1, ChecksumAlgorithm::Sha256]);
+
+        assert_eq!(
+            map.keys().collect::<Vec<_>>(),
+            vec![
+                "artifact-id-1.0.0-sources.jar.sha1",
+                "artifact-id-1.0.0-sources.jar.sha256",
+                "artifact-id-1.0.0.pom.sha1",
+                "artifact-id-1.0.0.pom.sha256",
+            ]
+        );
+        assert_eq!(
+            map["artifact-id-1.0.0-sources.jar.sha1"],
+            result.checksum(ChecksumAlgorithm::Sha1)
+        );
+        assert_eq!(
+            map["artifact-id-1.0.0.pom.sha256"].to_string(),
+            "id.group:artifact-id:1.0.0:pom.sha256"
+        );
+    }
+
+    #[test]
+    fn it_allows_only_listed_packagings() {
+        let allowed = ["pom", "war"];
+
+        let result =
+            Coordinates::new_with_allowed_packagings("id.group:artifact-id:1.0.0:war", &allowed)
+                .expect("Parsing failed");
+
+        assert_eq!(result.packaging, "war");
+
+        assert!(
+            Coordinates::new_with_allowed_packagings("id.group:artifact-id:1.0.0", &allowed)
+                .is_ok()
+        );
+
+        let result =
+            Coordinates::new_with_allowed_packagings("id.group:artifact-id:1.0.0:ear", &allowed);
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::DisallowedPackaging("ear".to_string())
+        );
+    }
+
+    #[test]
+    fn it_round_trips_coursier_notation() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+        let notation = result.to_coursier();
+
+        assert_eq!(
+            notation,
+            "id.group:artifact-id:1.0.0-SNAPSHOT,classifier=classifier,type=ext"
+        );
+        assert_eq!(
+            Coordinates::from_coursier(&notation).expect("Parsing failed"),
+            result
+        );
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(result.to_coursier(), "id.group:artifact-id:1.0.0");
+        assert_eq!(
+            Coordinates::from_coursier("id.group:artifact-id:1.0.0,exclude=org.foo%%bar")
+                .expect("Parsing failed"),
+            result
+        );
+    }
+
+    #[test]
+    fn it_groups_by_artifact_dir() {
+        let coords = [
+            Coordinates::new("id.group:first:1.0.0").unwrap(),
+            Coordinates::new("id.group:second:1.0.0").unwrap(),
+            Coordinates::new("id.group:first:2.0.0:pom").unwrap(),
+            Coordinates::new("id.other:first:1.0.0").unwrap(),
+            Coordinates::new("id.group:first:1.0.0:jar:sources").unwrap(),
+        ];
+
+        let group: Vec<&Coordinates> = coords
+            .iter()
+            .filter(|c| c.same_artifact_dir(&coords[0]))
+            .collect();
+
+        assert_eq!(group, vec![&coords[0], &coords[2], &coords[4]]);
+    }
+
+    #[test]
+    fn it_parses_dependency_list_line() {
+        let result =
+            Coordinates::from_dependency_list_line("[INFO]    org.example:lib:jar:1.0:compile")
+                .expect("Parsing failed");
+
+        assert_eq!(result.coordinates.to_string(), "org.example:lib:1.0");
+        assert_eq!(result.scope.as_deref(), Some("compile"));
+
+        let result = Coordinates::from_dependency_list_line(
+            "   org.example:lib-tests:jar:tests:1.0:test -- module lib.tests",
+        )
+        .expect("Parsing failed");
+
+        assert_eq!(
+            result.coordinates.to_string(),
+            "org.example:lib-tests:1.0:jar:tests"
+        );
+        assert_eq!(result.scope.as_deref(), Some("test"));
+
+        let result = Coordinates::from_dependency_list_line("org.example:lib:jar:1.0");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::InvalidNotation("org.example:lib:jar:1.0".to_string())
+        );
+    }
+
+    #[test]
+    fn it_appends_file_suffix() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+
+        assert_eq!(
+            result.with_file_suffix(".lastModified").file_name(),
+            "artifact-id-1.0.0-sources.jar.lastModified"
+        );
+        assert_eq!(result.with_file_suffix("asc").packaging, "jar.asc");
+    }
+
+    #[test]
+    fn it_converts_to_bazel() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(result.to_bazel(), "id.group:artifact-id:1.0.0");
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0:aar").unwrap();
+
+        assert_eq!(result.to_bazel(), "id.group:artifact-id:aar:1.0.0");
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+
+        assert_eq!(result.to_bazel(), "id.group:artifact-id:jar:sources:1.0.0");
+    }
+
+    #[test]
+    fn it_compares_with_normalized_packaging() {
+        let implicit = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        let explicit = Coordinates::new("id.group:artifact-id:1.0.0:jar").unwrap();
+        let pom = Coordinates::new("id.group:artifact-id:1.0.0:pom").unwrap();
+
+        let mut empty = implicit.clone();
+        empty.packaging = String::new();
+
+        assert!(implicit.eq_normalized(&explicit));
+        assert!(empty.eq_normalized(&explicit));
+        assert!(!implicit.eq_normalized(&pom));
+        assert!(!empty.eq_normalized(&pom));
+
+        let upper = Coordinates::new("id.group:artifact-id:1.0.0:JAR").unwrap();
+
+        assert_ne!(upper, implicit);
+        assert!(upper.eq_normalized(&implicit));
+        assert!(upper.eq_normalized(&explicit));
+
+        let upper_pom = Coordinates::new("id.group:artifact-id:1.0.0:POM").unwrap();
+        assert!(!upper_pom.eq_normalized(&pom));
+
+        let bundle = Coordinates::new("id.group:artifact-id:1.0.0:bundle").unwrap();
+        assert_eq!(bundle.file_name(), implicit.file_name());
+        assert!(!bundle.eq_normalized(&implicit));
+    }
+
+    #[test]
+    fn it_keeps_git_describe_versions() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0-5-gabc123").expect("Parsing failed");
+
+        assert_eq!(result.version, "1.0-5-gabc123");
+        assert!(result.version_label.is_none());
+        assert_eq!(result.full_version(), "1.0-5-gabc123");
+
+        let result = Coordinates::new("id.group:artifact-id:1.0-rc-gamma").expect("Parsing failed");
+
+        assert_eq!(result.version, "1.0");
+        assert_eq!(result.version_label.as_deref(), Some("rc-gamma"));
+    }
+
+    #[test]
+    fn it_resolves_path_for_packaging() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0:war").unwrap();
+
+        assert_eq!(
+            result.relative_path_for_packaging("jar", '/'),
+            "id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar"
+        );
+        assert_eq!(
+            result.relative_path_for_packaging("pom", '/'),
+            "id/group/artifact-id/1.0.0/artifact-id-1.0.0.pom"
+        );
+        assert_eq!(result.packaging, "war");
+    }
+
+    #[test]
+    fn it_detects_siblings() {
+        let main = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        let sources = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+        let javadoc = Coordinates::new("id.group:artifact-id:1.0.0:jar:javadoc").unwrap();
+        let other_version = Coordinates::new("id.group:artifact-id:2.0.0:jar:sources").unwrap();
+
+        assert!(main.is_sibling_of(&sources));
+        assert!(sources.is_sibling_of(&javadoc));
+        assert!(!main.is_sibling_of(&main));
+        assert!(!main.is_sibling_of(&other_version));
+        assert!(!sources.is_sibling_of(&other_version));
+    }
+
+    #[test]
+    fn it_round_trips_field_map() {
+        let result =
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+
+        let map: BTreeMap<String, String> = result
+            .to_field_map()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        assert_eq!(
+            Coordinates::from_mapping(&map).expect("Parsing failed"),
+            result
+        );
+
+        let mut map = BTreeMap::new();
+        map.insert("groupId".to_string(), "id.group".to_string());
+        map.insert("artifactId".to_string(), "artifact-id".to_string());
+
+        let result = Coordinates::from_mapping(&map);
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingVersion);
+    }
+
+    #[test]
+    fn it_resolves_next_snapshot_build() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT").unwrap();
+
+        assert_eq!(
+            result.next_snapshot_build(3, "20231201.101530"),
+            "1.0.0-20231201.101530-4"
+        );
+        assert_eq!(
+            result.next_snapshot_build(0, "20231201.101530"),
+            "1.0.0-20231201.101530-1"
+        );
+    }
+
+    #[test]
+    fn it_converts_group_to_domain() {
+        let result = Coordinates::new("com.example.foo:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(result.group_as_domain().as_deref(), Some("foo.example.com"));
+
+        for group_id in &[
+            "junit",
+            "com.example_foo",
+            "com..example",
+            "123.example",
+            "com.-foo",
+        ] {
+            let mut result = result.clone();
+            result.group_id = group_id.to_string();
+
+            assert!(
+                result.group_as_domain().is_none(),
+                "{} is not a domain",
+                group_id
+            );
+        }
+    }
+
+    #[test]
+    fn it_parses_from_str() {
+        let result: Coordinates = "id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier"
+            .parse()
+            .expect("Parsing failed");
+
+        assert_eq!(
+            result,
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap()
+        );
+
+        let result = "id.group:artifact-id".parse::<Coordinates>();
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingVersion);
+    }
+
+    #[test]
+    fn it_parses_timestamped_snapshots() {
+        let result =
+            Coordinates::new("org.example:lib:1.0-20231201.101530-3").expect("Parsing failed");
+
+        assert!(result.is_snapshot());
+        assert_eq!(
+            result.snapshot_timestamp(),
+            Some(("20231201.101530".to_string(), 3))
+        );
+        assert_eq!(
+            result.next_snapshot_build(3, "20231202.080000"),
+            "1.0-20231202.080000-4"
+        );
+
+        let result = Coordinates::new("org.example:lib:1.0-SNAPSHOT").expect("Parsing failed");
+
+        assert!(result.is_snapshot());
+        assert!(result.snapshot_timestamp().is_none());
+
+        let result = Coordinates::new("org.example:lib:1.0-rc-3").expect("Parsing failed");
+
+        assert!(!result.is_snapshot());
+        assert!(result.snapshot_timestamp().is_none());
+    }
+
+    #[test]
+    fn it_resolves_pom_leniently_and_strictly() {
+        let classified = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+
+        assert_eq!(classified.pom().file_name(), "artifact-id-1.0.0.pom");
+        assert_eq!(classified.try_pom(), Err(CoordinatesError::ClassifiedPom));
+
+        let main = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+
+        assert_eq!(main.try_pom(), Ok(main.pom()));
+
+        let result = Coordinates::new_strict("id.group:artifact-id:1.0.0:pom:sources");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::ClassifiedPom);
+    }
+
+    #[test]
+    fn it_reports_offending_component() {
+        let result = Coordinates::new("id.group").expect_err("Parsing should fail");
+        assert_eq!(result, CoordinatesError::MissingArtifactId);
+
+        let result = Coordinates::new("").expect_err("Parsing should fail");
+        assert_eq!(result, CoordinatesError::MissingGroupId);
+
+        let result = Coordinates::new("id.group::1.0.0").expect_err("Parsing should fail");
+        assert_eq!(result, CoordinatesError::EmptyComponent("artifact ID"));
+        assert_eq!(result.to_string(), "artifact ID is empty");
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources:extra");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::TooManyComponents(
+                "id.group:artifact-id:1.0.0:jar:sources:extra".to_string()
+            )
+        );
+        assert_eq!(
+            result.to_string(),
+            "too many components in `id.group:artifact-id:1.0.0:jar:sources:extra`, expected at most five"
+        );
+    }
+
+    #[test]
+    fn it_treats_default_packaging_as_explicit() {
+        let implicit = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        let explicit = Coordinates::new("id.group:artifact-id:1.0.0:jar").unwrap();
+
+        assert_eq!(implicit, explicit);
+
+        let mut set = HashSet::new();
+        set.insert(implicit);
+        set.insert(explicit);
+        set.insert(Coordinates::new("id.group:artifact-id:1.0.0:pom").unwrap());
+
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn it_resolves_lazily() {
+        let coords = [
+            Coordinates::new("id.group:artifact-id:1.0.0").unwrap(),
+            Coordinates::new("id.group:artifact-id:1.0.0:pom").unwrap(),
+        ];
+
+        let mut urls = resolve_iter(&coords, "https://example.com/maven");
+
+        assert_eq!(
+            urls.next().as_deref(),
+            Some("https://example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar")
+        );
+        assert_eq!(
+            urls.next().as_deref(),
+            Some("https://example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0.pom")
+        );
+        assert_eq!(urls.next(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_serializes_to_coordinates_string() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:zip:sources").unwrap();
+
+        let json = serde_json::to_string(&vec![coords.clone()]).unwrap();
+        assert_eq!(json, r#"["id.group:artifact-id:1.0.0:zip:sources"]"#);
+
+        let result: Vec<Coordinates> = serde_json::from_str(&json).unwrap();
+        assert_eq!(result, vec![coords]);
+
+        let result = serde_json::from_str::<Coordinates>(r#""id.group:artifact-id""#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_resolves_gradle_module_siblings() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+
+        assert_eq!(
+            coords.gradle_module().file_name(),
+            "artifact-id-1.0.0.module"
+        );
+        assert_eq!(
+            coords
+                .gradle_module()
+                .checksum(ChecksumAlgorithm::Sha256)
+                .file_name(),
+            "artifact-id-1.0.0.module.sha256"
+        );
+
+        let siblings: Vec<String> = coords
+            .gradle_module_siblings()
+            .iter()
+            .map(Coordinates::file_name)
+            .collect();
+
+        assert_eq!(
+            siblings,
+            [
+                "artifact-id-1.0.0.module.md5",
+                "artifact-id-1.0.0.module.sha1",
+                "artifact-id-1.0.0.module.sha256",
+                "artifact-id-1.0.0.module.sha512",
+                "artifact-id-1.0.0.module.asc",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_builds_coordinates() {
+        let result = CoordinatesBuilder::new()
+            .group_id("id.group")
+            .artifact_id("artifact-id")
+            .version("1.0.0-SNAPSHOT")
+            .build()
+            .expect("Building failed");
+
+        assert_eq!(
+            result,
+            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT").unwrap()
+        );
+        assert_eq!(result.packaging, "jar");
+
+        let result = CoordinatesBuilder::new()
+            .group_id("id.group")
+            .artifact_id("artifact-id")
+            .version("1.0")
+            .version_label("rc-1")
+            .packaging("zip")
+            .classifier("dist")
+            .build()
+            .expect("Building failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0-rc-1:zip:dist");
+
+        let result = CoordinatesBuilder::new()
+            .group_id("id.group")
+            .version("1.0.0")
+            .build();
+        let result = result.expect_err("Building should fail");
+
+        assert_eq!(result, CoordinatesError::MissingArtifactId);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn it_parses_json_with_custom_keys() {
+        let json = r#"{"g": "id.group", "a": "artifact-id", "v": "1.0.0-SNAPSHOT", "x": 1}"#;
+
+        let result = Coordinates::from_json_with_keys(json, "g", "a", "v").expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0-SNAPSHOT");
+
+        let result =
+            Coordinates::from_json_with_keys(r#"{"g": "id.group", "a": 1}"#, "g", "a", "v");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingArtifactId);
+
+        let result = Coordinates::from_json_with_keys("[]", "g", "a", "v");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::InvalidNotation("[]".to_string()));
+    }
+
+    #[test]
+    fn it_generates_patch_series() {
+        let coords = Coordinates::new("id.group:artifact-id:1.2.7-SNAPSHOT+build.1").unwrap();
+
+        let series: Vec<String> = coords
+            .patch_series(2)
+            .iter()
+            .map(Coordinates::to_string)
+            .collect();
+
+        assert_eq!(
+            series,
+            [
+                "id.group:artifact-id:1.2.0-SNAPSHOT",
+                "id.group:artifact-id:1.2.1-SNAPSHOT",
+                "id.group:artifact-id:1.2.2-SNAPSHOT",
+            ]
+        );
+
+        let coords = Coordinates::new("id.group:artifact-id:1.2").unwrap();
+
+        assert!(coords.patch_series(2).is_empty());
+    }
+
+    #[test]
+    fn it_splits_version_at_first_dash() {
+        let cases = [
+            ("1.0.0", "1.0.0", None),
+            ("1.0.0-SNAPSHOT", "1.0.0", Some("SNAPSHOT")),
+            ("1.0-alpha-1", "1.0", Some("alpha-1")),
+            ("2.0-rc-2", "2.0", Some("rc-2")),
+        ];
+
+        for (full_version, version, version_label) in cases.iter() {
+            let result = Coordinates::new(format!("id.group:artifact-id:{}", full_version))
+                .expect("Parsing failed");
+
+            assert_eq!(result.version, *version);
+            assert_eq!(result.version_label.as_deref(), *version_label);
+            assert_eq!(result.full_version(), *full_version);
+        }
+    }
+
+    #[test]
+    fn it_detects_snapshots_case_insensitively() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-snapshot").unwrap();
+        assert!(result.is_snapshot());
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-alpha-Snapshot").unwrap();
+        assert!(result.is_snapshot());
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-20231001.123456-3").unwrap();
+        assert!(result.is_snapshot());
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        assert!(!result.is_snapshot());
+
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOTS").unwrap();
+        assert!(!result.is_snapshot());
+    }
+
+    #[test]
+    fn it_detects_primary_artifacts() {
+        let jar = Coordinates::new("id.group:artifact-id:1.0.0:jar").unwrap();
+        assert!(jar.is_primary_artifact());
+        assert!(jar.pom().is_primary_artifact());
+
+        let checksum = Coordinates::new("id.group:artifact-id:1.0.0:jar.sha1").unwrap();
+        assert!(!checksum.is_primary_artifact());
+
+        let signature = Coordinates::new("id.group:artifact-id:1.0.0:asc").unwrap();
+        assert!(!signature.is_primary_artifact());
+        assert!(!jar.with_file_suffix(".asc").is_primary_artifact());
+    }
+
+    #[test]
+    fn it_parses_repository_paths() {
+        let cases = [
+            "id.group:artifact-id:1.0.0",
+            "id.group:artifact-id:1.0-alpha-1:jar:sources",
+            "id.group:artifact-id:1.0.0-SNAPSHOT:pom",
+            "id.group:artifact-id:1.0.0:jar.sha1",
+            "id.group:artifact-id:1.0.0:tar.gz:dist",
+        ];
+
+        for case in cases.iter() {
+            let coords = Coordinates::new(*case).unwrap();
+            let result = Coordinates::from_path(&coords.as_path_with_separator('\\'), '\\')
+                .expect("Parsing failed");
+
+            assert_eq!(result, coords);
+        }
+
+        let result = Coordinates::from_path("/id/group/artifact-id/1.0.0/other-1.0.0.jar", '/');
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::InvalidNotation(
+                "/id/group/artifact-id/1.0.0/other-1.0.0.jar".to_string()
+            )
+        );
+
+        let result = Coordinates::from_path("artifact-id/1.0.0/artifact-id-1.0.0.jar", '/');
+        assert!(result.is_err());
+
+        let cases = [
+            "id/group/artifact-id/1.0/artifact-id-1.0.1.jar",
+            "id/group/artifact-id/1.0/artifact-id-1.0.1",
+            "id/group/artifact-id/1.0/artifact-id-1.01.jar",
+            "id/group/artifact-id/1.0/artifact-id-1.0.1-sources.jar",
+        ];
+
+        for case in cases.iter() {
+            let result = Coordinates::from_path(case, '/');
+            let result = result.expect_err("Parsing should fail");
+            assert_eq!(result, CoordinatesError::InvalidNotation(case.to_string()));
+        }
+
+        let result = Coordinates::from_path("id/group/artifact-id/1.0/artifact-id-1.0.7z", '/')
+            .expect("Parsing failed");
+        assert_eq!(result.packaging, "7z");
+    }
+
+    #[test]
+    fn it_parses_file_names() {
+        let result = Coordinates::from_file_name("commons-lang3-3.12.0.jar", "org.apache.commons")
+            .expect("Parsing failed");
+
+        assert_eq!(
+            result.to_string(),
+            "org.apache.commons:commons-lang3:3.12.0"
+        );
+
+        let result = Coordinates::from_file_name("artifact-1.0.0-SNAPSHOT-sources.jar", "id.group")
+            .expect("Parsing failed");
+
+        assert_eq!(result.artifact_id, "artifact");
+        assert_eq!(result.version, "1.0.0");
+        assert_eq!(result.version_label.as_deref(), Some("SNAPSHOT"));
+        assert_eq!(result.classifier.as_deref(), Some("sources"));
+
+        let result =
+            Coordinates::from_file_name("my-hyphenated-lib-2.0-rc-2-test-fixtures.zip", "id.group")
+                .expect("Parsing failed");
+
+        assert_eq!(
+            result.to_string(),
+            "id.group:my-hyphenated-lib:2.0-rc-2:zip:test-fixtures"
+        );
+
+        let result = Coordinates::from_file_name("artifact-sources.jar", "id.group");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingVersion);
+    }
+
+    #[test]
+    fn it_reuses_cached_parse_results() {
+        let mut cache = ParseCache::new();
+
+        let first = cache
+            .parse("id.group:artifact-id:1.0.0")
+            .expect("Parsing failed");
+        let second = cache
+            .parse("id.group:artifact-id:1.0.0")
+            .expect("Parsing failed");
+
+        assert_eq!(first, second);
+        assert_eq!(cache.misses(), 1);
+
+        let result = cache.parse("id.group:artifact-id");
+        assert_eq!(result, Err(CoordinatesError::MissingVersion));
+
+        let result = cache.parse("id.group:artifact-id");
+        assert_eq!(result, Err(CoordinatesError::MissingVersion));
+
+        assert_eq!(cache.misses(), 2);
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+
+        assert_eq!(cache.misses(), 0);
+        assert!(cache.is_empty());
+
+        cache
+            .parse("id.group:artifact-id:1.0.0")
+            .expect("Parsing failed");
+
+        assert_eq!(cache.misses(), 1);
+    }
+
+    #[test]
+    fn it_maps_packaging_to_file_extension() {
+        let cases = [
+            ("war", "war"),
+            ("ear", "ear"),
+            ("bundle", "jar"),
+            ("maven-plugin", "jar"),
+            ("pom", "pom"),
+        ];
+
+        for (packaging, extension) in cases.iter() {
+            let result = Coordinates::new(format!("id.group:artifact-id:1.0.0:{}", packaging))
+                .expect("Parsing failed");
+
+            assert_eq!(result.file_extension(), *extension);
+            assert_eq!(
+                result.to_path(),
+                format!("id/group/artifact-id/1.0.0/artifact-id-1.0.0.{}", extension)
+            );
+        }
+    }
+
+    #[test]
+    fn it_builds_platform_path() {
+        let result = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:jar:sources").unwrap();
+
+        assert_eq!(
+            result.to_path_buf(),
+            PathBuf::from(result.as_path_with_separator(MAIN_SEPARATOR))
+        );
+
+        if MAIN_SEPARATOR == '\\' {
+            assert_eq!(
+                result.to_path_buf().to_str(),
+                Some("id\\group\\artifact-id\\1.0.0-SNAPSHOT\\artifact-id-1.0.0-SNAPSHOT-sources.jar")
+            );
+        } else {
+            assert_eq!(
+                result.to_path_buf().to_str(),
+                Some("id/group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-SNAPSHOT-sources.jar")
+            );
+        }
+    }
+
+    #[test]
+    fn it_resolves_checksum_urls() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:war:classes").unwrap();
+
+        let cases = [
+            (ChecksumAlgorithm::Sha1, "sha1"),
+            (ChecksumAlgorithm::Md5, "md5"),
+            (ChecksumAlgorithm::Sha256, "sha256"),
+            (ChecksumAlgorithm::Sha512, "sha512"),
+        ];
+
+        for (algorithm, extension) in cases.iter() {
+            assert_eq!(
+                coords.checksum_file_name(*algorithm),
+                format!("artifact-id-1.0.0-classes.war.{}", extension)
+            );
+            assert_eq!(
+                coords.checksum_url("https://example.com/maven", *algorithm),
+                format!(
+                    "https://example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0-classes.war.{}",
+                    extension
+                )
+            );
+        }
+
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:bundle").unwrap();
+
+        assert_eq!(
+            coords.checksum_file_name(ChecksumAlgorithm::Sha1),
+            "artifact-id-1.0.0.jar.sha1"
+        );
+    }
+
+    #[test]
+    fn it_resolves_nexus_browse_url() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0+build 1").unwrap();
+
+        assert_eq!(
+            coords.resolve_nexus_browse("https://nexus.example.com/", "maven releases"),
+            "https://nexus.example.com/#browse/browse:maven%20releases:id%2Fgroup%2Fartifact-id%2F1.0.0%2Bbuild%201"
+        );
+    }
+
+    #[test]
+    fn it_resolves_signatures() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+
+        assert_eq!(
+            coords.signature_file_name(),
+            "artifact-id-1.0.0-sources.jar.asc"
+        );
+        assert_eq!(
+            coords.signature_url("https://example.com/maven"),
+            "https://example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0-sources.jar.asc"
+        );
+        assert_eq!(
+            coords.pom().signature_file_name(),
+            "artifact-id-1.0.0.pom.asc"
+        );
+        assert_eq!(
+            coords
+                .checksum(ChecksumAlgorithm::Sha1)
+                .signature_file_name(),
+            "artifact-id-1.0.0-sources.jar.sha1.asc"
+        );
+    }
+
+    #[test]
+    fn it_resolves_classpath_entry() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        let repo_root = Path::new("repository");
+
+        let mut expected = PathBuf::from("repository");
+        expected.push("id");
+        expected.push("group");
+        expected.push("artifact-id");
+        expected.push("1.0.0");
+        expected.push("artifact-id-1.0.0.jar");
+
+        assert_eq!(coords.classpath_entry(repo_root), expected);
+    }
+
+    #[test]
+    fn it_resolves_sibling_artifacts() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:war:classes").unwrap();
+
+        assert_eq!(coords.pom().file_name(), "artifact-id-1.0.0.pom");
+        assert_eq!(
+            coords.sources().file_name(),
+            "artifact-id-1.0.0-sources.jar"
+        );
+        assert_eq!(
+            coords.javadoc().file_name(),
+            "artifact-id-1.0.0-javadoc.jar"
+        );
+        assert_eq!(coords.to_string(), "id.group:artifact-id:1.0.0:war:classes");
+    }
+
+    #[test]
+    fn it_parses_exclusion_marker() {
+        let result = Dependency::from_entry("!org.example:badlib:1.0").expect("Parsing failed");
+
+        assert!(result.excluded);
+        assert_eq!(result.coordinates.to_string(), "org.example:badlib:1.0");
+
+        let result = Dependency::from_entry(" org.example:goodlib:2.0 ").expect("Parsing failed");
+
+        assert!(!result.excluded);
+        assert_eq!(result.coordinates.to_string(), "org.example:goodlib:2.0");
+
+        let result = Dependency::from_entry("!org.example:badlib");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingVersion);
+    }
+
+    #[test]
+    fn it_flattens_classifier() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").unwrap();
+        let result = coords.flattened_classifier();
+
+        assert_eq!(result.packaging, "sources.jar");
+        assert!(result.classifier.is_none());
+        assert_eq!(result.file_name(), "artifact-id-1.0.0.sources.jar");
+
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:pom").unwrap();
+
+        assert_eq!(coords.flattened_classifier(), coords);
+    }
+
+    #[test]
+    fn it_resolves_metadata_urls() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:jar:sources").unwrap();
+
+        assert_eq!(
+            coords.metadata_path(),
+            "id/group/artifact-id/maven-metadata.xml"
+        );
+        assert_eq!(
+            coords.metadata_url("https://example.com/maven"),
+            "https://example.com/maven/id/group/artifact-id/maven-metadata.xml"
+        );
+        assert_eq!(
+            coords.snapshot_metadata_url("https://example.com/maven/"),
+            "https://example.com/maven/id/group/artifact-id/1.0.0-SNAPSHOT/maven-metadata.xml"
+        );
+    }
+
+    #[test]
+    fn it_normalizes_separators() {
+        let path = normalize_separators("id\\group/artifact-id\\1.0.0/artifact-id-1.0.0.jar");
+
+        assert_eq!(path, "id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar");
+        assert_eq!(
+            Coordinates::from_path(&path, '/').expect("Parsing failed"),
+            Coordinates::new("id.group:artifact-id:1.0.0").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_keeps_multi_part_extensions() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0:tar.gz").unwrap();
+
+        assert_eq!(coords.file_name(), "artifact-id-1.0.tar.gz");
+        assert_eq!(Coordinates::new(coords.to_string()).unwrap(), coords);
+        assert_eq!(
+            Coordinates::from_path(&coords.to_path(), '/').expect("Parsing failed"),
+            coords
+        );
+
+        let cases = [
+            ("artifact-id-1.0.tar.gz", "1.0", "tar.gz", None),
+            ("artifact-id-1.0-dist.tar.xz", "1.0", "tar.xz", Some("dist")),
+            ("artifact-id-1.0.tar.gz.sha1", "1.0", "tar.gz.sha1", None),
+            ("artifact-id-1.0.jar.asc", "1.0", "jar.asc", None),
+            ("artifact-id-1.0.test-jar", "1.0", "test-jar", None),
+            ("artifact-id-1.0.gz", "1.0", "gz", None),
+        ];
+
+        assert!(Coordinates::from_file_name("a.gz", "id.group").is_err());
+
+        for (file_name, version, packaging, classifier) in cases.iter() {
+            let result =
+                Coordinates::from_file_name(file_name, "id.group").expect("Parsing failed");
+
+            assert_eq!(result.artifact_id, "artifact-id");
+            assert_eq!(result.version, *version);
+            assert_eq!(result.packaging, *packaging);
+            assert_eq!(result.classifier.as_deref(), *classifier);
+        }
+    }
+
+    #[test]
+    fn it_resolves_all_checksum_urls() {
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0:jar:tests").unwrap();
+        let algorithms = [ChecksumAlgorithm::Md5, ChecksumAlgorithm::Sha1];
+
+        let result = coords.all_checksum_urls("https://example.com/maven", &algorithms);
+
+        assert_eq!(result.len(), 8);
+        assert_eq!(
+            result[0],
+            "https://example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar.md5"
+        );
+        assert_eq!(
+            result[7],
+            "https://example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0-javadoc.jar.sha1"
+        );
+    }
+
+    #[test]
+    fn it_round_trips_explicit_packaging() {
+        let explicit = Coordinates::new("g:a:v:jar").unwrap();
+        let implicit = Coordinates::new("g:a:v").unwrap();
+
+        assert_eq!(explicit.to_string(), "g:a:v:jar");
+        assert_eq!(implicit.to_string(), "g:a:v");
+        assert_eq!(explicit.packaging, "jar");
+        assert_eq!(explicit, implicit);
+        assert_eq!(explicit.to_canonical_string(), "g:a:v");
+    }
+
+    #[test]
+    fn it_parses_gradle_notation() {
+        let cases = [
+            ("id.group:artifact-id:1.0", "id.group:artifact-id:1.0"),
+            (
+                "id.group:artifact-id:1.0@aar",
+                "id.group:artifact-id:1.0:aar",
+            ),
+            (
+                "id.group:artifact-id:1.0@zip",
+                "id.group:artifact-id:1.0:zip",
+            ),
+            (
+                "id.group:artifact-id:1.0:natives",
+                "id.group:artifact-id:1.0:jar:natives",
+            ),
+            (
+                "id.group:artifact-id:1.0:linux@so",
+                "id.group:artifact-id:1.0:so:linux",
+            ),
+        ];
+
+        for (notation, expected) in cases.iter() {
+            let result = Coordinates::from_gradle(notation).expect("Parsing failed");
+
+            assert_eq!(result.to_string(), *expected);
+        }
+
+        let result = Coordinates::from_gradle("id.group:artifact-id:1.0:jar:linux@so");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(
+            result,
+            CoordinatesError::TooManyComponents(
+                "id.group:artifact-id:1.0:jar:linux@so".to_string()
+            )
+        );
+
+        let result = Coordinates::from_gradle("id.group:artifact-id:1.0@");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::EmptyComponent("packaging"));
+    }
+
+    #[test]
+    fn it_parses_at_version_notation() {
+        let result = Coordinates::from_at_version("id.group:artifact-id@1.0.0-SNAPSHOT")
+            .expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0-SNAPSHOT");
+
+        let result = Coordinates::from_at_version("id.group:artifact-id:1.0.0");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingVersion);
+
+        let result = Coordinates::from_at_version("id.group@1.0.0");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::MissingArtifactId);
+
+        let result = Coordinates::from_at_version("id.group:artifact-id@");
+        let result = result.expect_err("Parsing should fail");
+
+        assert_eq!(result, CoordinatesError::EmptyComponent("version"));
+
+        let result = Coordinates::from_at_version("id.group:artifact-id:jar@1.0.0");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_parses_version_numbers() {
+        let result = Coordinates::new("id.group:artifact-id:1.2.3").unwrap();
+        assert_eq!(result.version_numbers(), [1, 2, 3]);
+
+        let result = Coordinates::new("id.group:artifact-id:10.0-rc-1").unwrap();
+        assert_eq!(result.version_numbers(), [10, 0]);
+
+        let result = Coordinates::new("id.group:artifact-id:1.2.x").unwrap();
+        assert!(result.version_numbers().is_empty());
+
+        let result = Coordinates::new("id.group:artifact-id:latest").unwrap();
+        assert!(result.version_numbers().is_empty());
+    }
+
+    #[test]
+    fn it_converts_to_gradle_notation() {
+        let cases = [
+            ("g:a:v", "g:a:v"),
+            ("g:a:v:jar", "g:a:v"),
+            ("g:a:v:jar:sources", "g:a:v:sources"),
+            ("g:a:v:zip", "g:a:v@zip"),
+            ("g:a:v:so:linux", "g:a:v:linux@so"),
+        ];
+
+        for (coordinates, expected) in cases.iter() {
+            let coords = Coordinates::new(*coordinates).unwrap();
+
+            assert_eq!(coords.to_gradle(), *expected);
+            assert_eq!(Coordinates::from_gradle(expected).unwrap(), coords);
+        }
+    }
+
+    #[test]
+    fn it_encodes_resolved_urls() {
+        let coords =
+            Coordinates::new("id.group:artifact-id:1.0.0+build.5:jar:my classifier").unwrap();
+
+        assert_eq!(
+            coords.resolve("https://example.com/maven"),
+            "https://example.com/maven/id/group/artifact-id/1.0.0%2Bbuild.5/artifact-id-1.0.0%2Bbuild.5-my%20classifier.jar"
+        );
+        assert_eq!(
+            coords.to_path(),
+            "id/group/artifact-id/1.0.0+build.5/artifact-id-1.0.0+build.5-my classifier.jar"
+        );
+    }
+
+    #[test]
+    fn it_guards_against_path_traversal() {
+        let root = Path::new("repository");
+
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+        assert!(coords.is_safe_relative_to(root));
+
+        let coords = Coordinates::new("id.group:..:1.0.0").unwrap();
+        assert!(coords.is_safe_relative_to(root));
+
+        // `..` climbing above the root through the group ID directories
+        let coords = Coordinates::new("id.group:../../..:1.0.0").unwrap();
+        assert!(!coords.is_safe_relative_to(root));
+
+        // `..` staying within the group ID directories
+        let coords = Coordinates::new("id.group.nested:../..:1.0.0").unwrap();
+        assert!(coords.is_safe_relative_to(root));
+
+        // Dots split `..` in the group ID, leaving absolute segments
+        let coords = Coordinates::new("id/../../../etc:artifact-id:1.0.0").unwrap();
+        assert!(coords
+            .group_segments()
+            .all(|segment| !segment.split('/').any(|s| s == "..")));
+        assert!(!coords.is_safe_relative_to(root));
+
+        let coords = Coordinates::new("/etc:artifact-id:1.0.0").unwrap();
+        assert!(!coords.is_safe_relative_to(root));
+
+        let coords = Coordinates::new("id./etc:artifact-id:1.0.0").unwrap();
+        assert!(!coords.is_safe_relative_to(root));
+
+        let coords = Coordinates::new("id.group:artifact-id:/etc/passwd").unwrap();
+        assert!(!coords.is_safe_relative_to(root));
+    }
+
+    #[test]
+    fn it_rebases_all_coordinates() {
+        let coords = [
+            Coordinates::new("id.group:artifact-id:1.0.0").unwrap(),
+            Coordinates::new("id.group:artifact-id:1.0.0:pom").unwrap(),
+            Coordinates::new("org.example:lib:2.0:jar:sources").unwrap(),
+        ];
+
+        assert_eq!(
+            rebase_all(&coords, "https://mirror.example.com/maven"),
+            [
+                "https://mirror.example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0.jar",
+                "https://mirror.example.com/maven/id/group/artifact-id/1.0.0/artifact-id-1.0.0.pom",
+                "https://mirror.example.com/maven/org/example/lib/2.0/lib-2.0-sources.jar",
+            ]
+        );
+        assert!(rebase_all(&[], "https://mirror.example.com/maven").is_empty());
+    }
+
+    #[test]
+    fn it_compares_versions() {
+        assert_eq!(compare_versions("1.9", "1.10"), Ordering::Less);
+        assert_eq!(compare_versions("1.0-alpha", "1.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.0-SNAPSHOT", "1.0"), Ordering::Less);
+        assert_eq!(
+            compare_versions("1.0-alpha-1", "1.0-alpha-2"),
+            Ordering::Less
+        );
+        assert_eq!(compare_versions("1.0-beta", "1.0-RC1"), Ordering::Less);
+        assert_eq!(compare_versions("1.0-rc-1", "1.0-SNAPSHOT"), Ordering::Less);
+        assert_eq!(compare_versions("1.0-sp", "1.0"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0", "1.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0.Final", "1.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0.1", "1.0-sp"), Ordering::Greater);
+        assert_eq!(compare_versions("10", "9"), Ordering::Greater);
+    }
+
+    #[test]
+    fn it_orders_coordinates_by_version() {
+        let mut coords: Vec<Coordinates> = [
+            "id.group:artifact-id:1.10",
+            "id.group:artifact-id:1.0",
+            "id.group:artifact-id:1.0-SNAPSHOT",
+            "id.group:artifact-id:1.9",
+            "id.group:artifact-id:1.0-alpha",
+            "id.group:a-artifact:2.0",
+        ]
+        .iter()
+        .map(|c| Coordinates::new(*c).unwrap())
+        .collect();
+
+        coords.sort();
+
+        let result: Vec<String> = coords.iter().map(Coordinates::to_string).collect();
+
+        assert_eq!(
+            result,
+            [
+                "id.group:a-artifact:2.0",
+                "id.group:artifact-id:1.0-alpha",
+                "id.group:artifact-id:1.0-SNAPSHOT",
+                "id.group:artifact-id:1.0",
+                "id.group:artifact-id:1.9",
+                "id.group:artifact-id:1.10",
+            ]
+        );
+
+        let implicit = Coordinates::new("id.group:artifact-id:1.0").unwrap();
+        let explicit = Coordinates::new("id.group:artifact-id:1.0:jar").unwrap();
+
+        assert_eq!(implicit.cmp(&explicit), Ordering::Equal);
+        assert!(implicit < Coordinates::new("id.group:artifact-id:1.0:pom").unwrap());
+    }
+
+    #[test]
+    fn it_validates_naming_rules() {
+        let cases = [
+            ("id..group:artifact-id:1.0.0", "group ID"),
+            (".id.group:artifact-id:1.0.0", "group ID"),
+            ("id group:artifact-id:1.0.0", "group ID"),
+            ("id.group:artifact id:1.0.0", "artifact ID"),
+            ("id.group:artifact/id:1.0.0", "artifact ID"),
+            ("id.group:artifact-id:1.0/1", "version"),
+            ("id.group:artifact-id:1.0.0:jar:linux\\x86", "classifier"),
+        ];
+
+        for (coordinates, component) in cases.iter() {
+            let result = Coordinates::new(*coordinates).expect("Parsing failed");
+
+            assert_eq!(
+                result.validate(),
+                Err(CoordinatesError::MalformedComponent(component))
+            );
+        }
+
+        let result = Coordinates::new_quoted(r#"id.group:"artifact:id":1.0.0"#).unwrap();
+        assert_eq!(
+            result.validate(),
+            Err(CoordinatesError::MalformedComponent("artifact ID"))
+        );
+
+        let result = Coordinates::new("id.group:artifact_id:1.0.0-rc.1+build.5:tar.gz:linux-x86")
+            .expect("Parsing failed");
+
+        assert_eq!(result.validate(), Ok(()));
+    }
+
+    #[test]
+    fn it_parses_aether_artifacts() {
+        let result =
+            Coordinates::from_aether("id.group:artifact-id:1.0.0").expect("Parsing failed");
+
         assert_eq!(result.version, "1.0.0");
+        assert_eq!(result.packaging, "jar");
+        assert_eq!(result.classifier, None);
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0");
+
+        let result =
+            Coordinates::from_aether("id.group:artifact-id:pom:1.0.0").expect("Parsing failed");
+
+        assert_eq!(result.version, "1.0.0");
+        assert_eq!(result.packaging, "pom");
+        assert_eq!(result.classifier, None);
+
+        let result =
+            Coordinates::from_aether("id.group:artifact-id:jar:1.0.0").expect("Parsing failed");
+
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0:jar");
+
+        let result = Coordinates::from_aether("id.group:artifact-id:zip:natives:1.0.0-rc.1")
+            .expect("Parsing failed");
+
+        assert_eq!(result.version, "1.0.0");
+        assert_eq!(result.version_label, Some("rc.1".to_string()));
+        assert_eq!(result.packaging, "zip");
+        assert_eq!(result.classifier, Some("natives".to_string()));
+    }
+
+    #[test]
+    fn it_rejects_malformed_aether_artifacts() {
+        let result = Coordinates::from_aether("id.group:artifact-id");
+        let result = result.expect_err("Parsing should fail");
+        assert_eq!(result, CoordinatesError::MissingVersion);
+
+        let result = Coordinates::from_aether(":artifact-id:1.0.0");
+        let result = result.expect_err("Parsing should fail");
+        assert_eq!(result, CoordinatesError::EmptyComponent("group ID"));
+
+        let input = "id.group:artifact-id:zip:natives:extra:1.0.0";
+        let result = Coordinates::from_aether(input);
+        let result = result.expect_err("Parsing should fail");
         assert_eq!(
-            result.version_label.expect("Version label not parsed"),
-            "SNAPSHOT"
+            result,
+            CoordinatesError::TooManyComponents(input.to_string())
         );
-        assert_eq!(result.packaging, "ext");
+    }
+
+    #[test]
+    fn it_converts_to_aether_artifact() {
+        let cases = [
+            ("g:a:v", "g:a:v"),
+            ("g:a:v:jar", "g:a:v"),
+            ("g:a:v-rc.1", "g:a:v-rc.1"),
+            ("g:a:v:zip", "g:a:zip:v"),
+            ("g:a:v:jar:sources", "g:a:jar:sources:v"),
+            ("g:a:v:so:linux", "g:a:so:linux:v"),
+        ];
+
+        for (coordinates, expected) in cases.iter() {
+            let coords = Coordinates::new(*coordinates).unwrap();
+
+            assert_eq!(coords.to_aether(), *expected);
+            assert_eq!(Coordinates::from_aether(expected).unwrap(), coords);
+        }
+    }
+
+    #[test]
+    fn it_splits_group_id() {
+        let coords = Coordinates::new("io.github.brawaru:artifact:1.0.0").expect("Parsing failed");
+
         assert_eq!(
-            result.classifier.expect("Classifier not parsed"),
-            "classifier"
+            coords.group_segments().collect::<Vec<_>>(),
+            vec!["io", "github", "brawaru"]
         );
+        assert_eq!(coords.group_id_path('/'), "io/github/brawaru");
+        assert_eq!(coords.group_id_path('\\'), "io\\github\\brawaru");
+
+        let coords = Coordinates::new("brawaru:artifact:1.0.0").expect("Parsing failed");
+
+        assert_eq!(coords.group_segments().collect::<Vec<_>>(), vec!["brawaru"]);
+        assert_eq!(coords.group_id_path('/'), "brawaru");
     }
 
     #[test]
-    fn it_parses_partial_values() {
-        let result = Coordinates::new("id.group:artifact-id:1.0.0").expect("Parsing failed");
+    fn it_resolves_coursier_cache_path() {
+        let coords =
+            Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").expect("Parsing failed");
+
+        let path = coords.coursier_cache_path(Path::new("cache"), "repo1.maven.org/maven2/");
+
+        assert_eq!(
+            path,
+            [
+                "cache",
+                "repo1.maven.org",
+                "maven2",
+                "id",
+                "group",
+                "artifact-id",
+                "1.0.0",
+                "artifact-id-1.0.0-sources.jar",
+            ]
+            .iter()
+            .collect::<PathBuf>()
+        );
+
+        let path = coords.coursier_cache_path(Path::new("cache"), "example.com");
+        assert!(path.starts_with(Path::new("cache").join("example.com").join("id")));
+    }
+
+    #[test]
+    fn it_parses_borrowed_coordinates() {
+        let input = "maven:id.group:artifact-id:1.0.0-rc.1+build.5:bundle:sources";
+        let result = CoordinatesRef::parse(input).expect("Parsing failed");
 
         assert_eq!(result.group_id, "id.group");
         assert_eq!(result.artifact_id, "artifact-id");
         assert_eq!(result.version, "1.0.0");
-        assert!(result.version_label.is_none());
-        assert_eq!(result.packaging, crate::MAVEN_STANDARD_PACKAGING);
-        assert!(result.classifier.is_none());
+        assert_eq!(result.version_label, Some("rc.1"));
+        assert_eq!(result.build_metadata, Some("build.5"));
+        assert_eq!(result.packaging, "bundle");
+        assert_eq!(result.classifier, Some("sources"));
+        assert_eq!(result.full_version(), "1.0.0-rc.1+build.5");
+        assert_eq!(result.file_extension(), "jar");
+        assert_eq!(
+            result.file_name(),
+            "artifact-id-1.0.0-rc.1+build.5-sources.jar"
+        );
+
+        let owned = Coordinates::new(input).expect("Parsing failed");
+
+        assert_eq!(result.file_basename(), owned.file_basename());
+        assert_eq!(result.into_owned(), owned);
+        assert_eq!(
+            Coordinates::from(CoordinatesRef::parse("g:a:1.0:jar").unwrap()).to_string(),
+            "g:a:1.0:jar"
+        );
     }
 
     #[test]
-    fn it_requires_general_data() {
-        let result = Coordinates::new("id.group:artifact-id");
-        let result = result.expect_err("Parsing should fail");
+    fn it_rejects_malformed_borrowed_coordinates() {
+        let cases = [
+            ("", CoordinatesError::MissingGroupId),
+            ("id.group", CoordinatesError::MissingArtifactId),
+            ("id.group:artifact-id", CoordinatesError::MissingVersion),
+            (
+                "id.group::1.0.0",
+                CoordinatesError::EmptyComponent("artifact ID"),
+            ),
+            (
+                "g:a:v:jar:sources:extra",
+                CoordinatesError::TooManyComponents("g:a:v:jar:sources:extra".to_string()),
+            ),
+        ];
 
-        assert_eq!(result, ErrorKind::InvalidInput);
+        for (input, expected) in cases.iter() {
+            let result = CoordinatesRef::parse(input);
+            let result = result.expect_err("Parsing should fail");
+            assert_eq!(result, *expected);
+        }
     }
 
     #[test]
-    fn it_resolves_file_name_all() {
-        let result =
-            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+    fn it_sorts_coordinates_paired_with_values() {
+        let coords = |notation: &str| Coordinates::new(notation).expect("Parsing failed");
+
+        let mut items = vec![
+            (coords("id.group:artifact-id:1.10.0"), 30),
+            (coords("id.group:artifact-id:1.2.0"), 20),
+            (coords("id.group:artifact-id:1.10.0"), 10),
+            (coords("id.group:artifact-id:1.2.0-SNAPSHOT"), 40),
+        ];
+
+        sort_by_version_then(&mut items);
+
+        let sorted: Vec<(String, i32)> = items
+            .into_iter()
+            .map(|(coords, value)| (coords.to_string(), value))
+            .collect();
 
         assert_eq!(
-            result.file_basename(),
-            "artifact-id-1.0.0-SNAPSHOT-classifier"
+            sorted,
+            vec![
+                ("id.group:artifact-id:1.2.0-SNAPSHOT".to_string(), 40),
+                ("id.group:artifact-id:1.2.0".to_string(), 20),
+                ("id.group:artifact-id:1.10.0".to_string(), 10),
+                ("id.group:artifact-id:1.10.0".to_string(), 30),
+            ]
         );
+    }
+
+    #[test]
+    fn it_derives_coordinates_with_setters() {
+        let base = Coordinates::new("id.group:artifact-id:1.0.0").expect("Parsing failed");
+
+        let result = base
+            .clone()
+            .with_classifier("natives")
+            .with_packaging("zip")
+            .with_version("2.0.0-rc.1+build.5");
 
+        assert_eq!(result.version, "2.0.0");
+        assert_eq!(result.version_label, Some("rc.1".to_string()));
+        assert_eq!(result.build_metadata, Some("build.5".to_string()));
         assert_eq!(
-            result.file_name(),
-            "artifact-id-1.0.0-SNAPSHOT-classifier.ext"
+            result.to_string(),
+            "id.group:artifact-id:2.0.0-rc.1+build.5:zip:natives"
         );
+
+        let result = result.with_version("2.0.0");
+
+        assert_eq!(result.version_label, None);
+        assert_eq!(result.build_metadata, None);
+
+        let result = base.with_version_label(Some("SNAPSHOT".to_string()));
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0-SNAPSHOT");
+
+        let result = result.with_version_label(None);
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0");
     }
 
     #[test]
-    fn it_resolves_file_name_partial() {
-        let result = Coordinates::new("id.group:artifact-id:1.0.0").unwrap();
+    fn it_converts_to_slug() {
+        let coords =
+            Coordinates::new("id.group:artifact-id:1.0.0:jar:sources").expect("Parsing failed");
+        assert_eq!(
+            coords.to_slug(),
+            "id.group__artifact-id__1.0.0__jar__sources"
+        );
 
-        assert_eq!(result.file_basename(), "artifact-id-1.0.0");
-        assert_eq!(result.file_name(), "artifact-id-1.0.0.jar");
+        let coords = Coordinates::new("id.group:artifact-id:1.0.0").expect("Parsing failed");
+        assert_eq!(coords.to_slug(), "id.group__artifact-id__1.0.0__jar");
+
+        let coords = Coordinates::new("id.group:artifact_id:1.0/0+build:tar.gz:linux x86")
+            .expect("Parsing failed");
+        assert_eq!(
+            coords.to_slug(),
+            "id.group__artifact%5Fid__1.0%2F0%2Bbuild__tar.gz__linux%20x86"
+        );
+
+        let first = Coordinates::new("a_:b:1.0").expect("Parsing failed");
+        let second = Coordinates::new("a:_b:1.0").expect("Parsing failed");
+        assert_ne!(first.to_slug(), second.to_slug());
     }
 
     #[test]
-    fn it_resolves_path() {
-        let result =
-            Coordinates::new("id.group:artifact-id:1.0.0-SNAPSHOT:ext:classifier").unwrap();
+    fn it_normalizes_group_and_artifact_ids() {
+        let coords = Coordinates::new("ID.Group:Artifact-ID:1.0.0-RC1:JAR:Linux-x86")
+            .expect("Parsing failed");
+
+        let result = coords.normalized();
+
+        assert_eq!(result.group_id, "id.group");
+        assert_eq!(result.artifact_id, "artifact-id");
+        assert_eq!(result.version_label, Some("RC1".to_string()));
+        assert_eq!(result.packaging, "JAR");
+        assert_eq!(result.classifier, Some("Linux-x86".to_string()));
+        assert_eq!(
+            result.to_string(),
+            "id.group:artifact-id:1.0.0-RC1:JAR:Linux-x86"
+        );
+
+        assert_eq!(coords.group_id, "ID.Group");
+    }
+
+    #[test]
+    fn it_parses_repository_paths_under_root() {
+        let path = r"C:\repo\id\group\artifact-id\1.0.0\artifact-id-1.0.0-sources.jar";
+
+        let result = Coordinates::from_path_under(r"C:\repo\", path, '\\').expect("Parsing failed");
+        assert_eq!(result.to_string(), "id.group:artifact-id:1.0.0:jar:sources");
+
+        let result = Coordinates::from_path_under(r"C:\repo", path, '\\').expect("Parsing failed");
+        assert_eq!(result.group_id, "id.group");
+
+        let result = Coordinates::from_path_under(r"C:\rep", path, '\\');
+        let result = result.expect_err("Parsing should fail");
+        assert_eq!(result, CoordinatesError::InvalidNotation(path.to_string()));
+
+        let result = Coordinates::from_path_under(r"D:\repo", path, '\\');
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_parses_unique_snapshot_repository_paths() {
+        let result = Coordinates::from_path(
+            "id/group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-20231001.123456-3.jar",
+            '/',
+        )
+        .expect("Parsing failed");
 
+        assert_eq!(result.version, "1.0.0");
+        assert_eq!(result.version_label, Some("20231001.123456-3".to_string()));
         assert_eq!(
-            result.to_path(),
-            "id/group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-SNAPSHOT-classifier.ext"
+            result.snapshot_timestamp(),
+            Some(("20231001.123456".to_string(), 3))
+        );
+        assert_eq!(result.classifier, None);
+
+        let result = Coordinates::from_path(
+            "id/group/artifact-id/1.0-alpha-SNAPSHOT/artifact-id-1.0-alpha-20231001.123456-12-sources.jar.sha1",
+            '/',
+        )
+        .expect("Parsing failed");
+
+        assert_eq!(result.full_version(), "1.0-alpha-20231001.123456-12");
+        assert_eq!(result.classifier, Some("sources".to_string()));
+        assert_eq!(result.packaging, "jar.sha1");
+
+        let result = Coordinates::from_path(
+            "id/group/artifact-id/1.0.0-SNAPSHOT/artifact-id-1.0.0-20231001-3.jar",
+            '/',
         );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_recognizes_uppercase_packagings() {
+        let cases = [
+            ("JAR", "jar"),
+            ("Pom", "pom"),
+            ("BUNDLE", "jar"),
+            ("Maven-Plugin", "jar"),
+            ("TAR.GZ", "tar.gz"),
+            ("DMG", "DMG"),
+        ];
+
+        for (packaging, expected) in cases.iter() {
+            let coords = Coordinates::new(format!("id.group:artifact-id:1.0.0:{}", packaging))
+                .expect("Parsing failed");
+
+            assert_eq!(coords.file_extension_with_case(false), *expected);
+            assert_eq!(coords.file_extension_with_case(true), *packaging);
+            assert_eq!(coords.file_extension(), *packaging);
+        }
+
+        let result =
+            CoordinatesRef::parse("id.group:artifact-id:1.0.0:BUNDLE").expect("Parsing failed");
+
+        assert_eq!(result.file_extension(), "BUNDLE");
+        assert_eq!(result.file_extension_with_case(false), "jar");
     }
 }